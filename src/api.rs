@@ -1,5 +1,8 @@
 //! API client for Revelation backend services.
 
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use futures::future::join_all;
 use gloo_net::http::Request;
 use revelation_bible::{
     Book, ChapterInfo, DailyReading, Pericope, SearchResult, Testament, Verse
@@ -8,7 +11,13 @@ use revelation_songbook::{Song, SongSearchResult, SongSummary, Songbook, Songboo
 use revelation_user::RUser;
 use uuid::Uuid;
 
-use crate::bible::BibleProvider;
+use crate::{
+    bible::BibleProvider,
+    daily::DailySchedule,
+    query::Query,
+    search::{SearchFilter, SearchHit},
+    versification::VerseKey
+};
 
 fn api_base() -> String {
     let host = web_sys::window()
@@ -136,26 +145,110 @@ pub async fn get_chapters_info(book_id: i16) -> Result<Vec<ChapterInfo>, String>
     response.json().await.map_err(|e| e.to_string())
 }
 
-/// Searches Bible text.
+/// Searches Bible text within the filtered books.
+///
+/// The books are sent as `books=1,2,3`; results are filtered again here in
+/// case the server ignores them.
 ///
 /// # Errors
 ///
 /// Returns error string if network request or JSON parsing fails.
-pub async fn search_bible(query: &str) -> Result<Vec<SearchResult>, String> {
-    let url = format!("{}/bible/search?q={query}", api_base());
-    let response = Request::get(&url).send().await.map_err(|e| e.to_string())?;
+pub async fn search_bible(
+    query: &str,
+    filter: &SearchFilter
+) -> Result<Vec<SearchResult>, String> {
+    let books: Vec<String> = filter.books().map(|id| id.to_string()).collect();
+    let mut params = vec![("q", query.to_string())];
+    if !books.is_empty() {
+        params.push(("books", books.join(",")));
+    }
+    let response = Request::get(&format!("{}/bible/search", api_base()))
+        .query(params)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
 
-    response.json().await.map_err(|e| e.to_string())
+    let mut results: Vec<SearchResult> = response.json().await.map_err(|e| e.to_string())?;
+    results.retain(|r| filter.allows(r.verse.book_id));
+    Ok(results)
+}
+
+/// Runs a parsed query through the plain-text search.
+///
+/// Plain text is sent as is and trusted like before. Otherwise each plain
+/// text part is searched separately so the server decides what it matches,
+/// phrases and wildcards are searched by their anchors and checked here,
+/// and the hits are merged in canonical order. The requests run
+/// concurrently.
+///
+/// # Errors
+///
+/// Returns error string if any of the requests fails.
+pub async fn search_bible_query(
+    query: &Query,
+    filter: &SearchFilter
+) -> Result<Vec<SearchHit>, String> {
+    if let Query::Text(text) = query {
+        let results = search_bible(text, filter).await?;
+        return Ok(results
+            .into_iter()
+            .map(|r| SearchHit::new(r.verse, query))
+            .collect());
+    }
+
+    let anchors = query.anchors();
+    let mut literals: Vec<&str> = anchors.iter().map(String::as_str).collect();
+    literals.extend(query.texts());
+    literals.sort_unstable();
+    literals.dedup();
+
+    let mut found: HashMap<&str, HashSet<VerseKey>> = HashMap::new();
+    let mut candidates: BTreeMap<VerseKey, Verse> = BTreeMap::new();
+    let requests = literals.iter().map(|literal| search_bible(literal, filter));
+    let responses = join_all(requests).await;
+    for (literal, results) in literals.into_iter().zip(responses) {
+        let results = results?;
+        let keys = results
+            .iter()
+            .map(|r| (r.verse.book_id, r.verse.chapter, r.verse.verse))
+            .collect();
+        found.insert(literal, keys);
+        if anchors.iter().any(|a| a == literal) {
+            candidates.extend(
+                results
+                    .into_iter()
+                    .map(|r| ((r.verse.book_id, r.verse.chapter, r.verse.verse), r.verse))
+            );
+        }
+    }
+
+    Ok(candidates
+        .into_iter()
+        .filter(|(key, verse)| {
+            query.matches_with(&verse.text, |text| {
+                found.get(text).is_some_and(|keys| keys.contains(key))
+            })
+        })
+        .map(|(_, verse)| SearchHit::new(verse, query))
+        .collect())
 }
 
 /// Fetches symphony (concordance) data for a word.
 ///
 /// # Errors
 ///
-/// Returns error string if network request or JSON parsing fails.
+/// Returns error string if network request or JSON parsing fails; an
+/// unreachable server is reported as `Network error: ...`.
 pub async fn get_symphony(word: &str) -> Result<SymphonyResponse, String> {
     let url = format!("{}/bible/symphony/{word}", api_base());
-    let response = Request::get(&url).send().await.map_err(|e| e.to_string())?;
+    let response = Request::get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Network error: {e}"))?;
+
+    if !response.ok() {
+        return Err(format!("HTTP error: {}", response.status()));
+    }
 
     response.json().await.map_err(|e| e.to_string())
 }
@@ -183,6 +276,22 @@ pub async fn get_today_reading() -> Result<Option<DailyReading>, String> {
     response.json().await.map_err(|e| e.to_string())
 }
 
+/// Fetches the whole daily reading schedule.
+///
+/// # Errors
+///
+/// Returns error string if network request or JSON parsing fails.
+pub async fn get_daily_schedule() -> Result<DailySchedule, String> {
+    let url = format!("{}/bible/schedule", api_base());
+    let response = Request::get(&url).send().await.map_err(|e| e.to_string())?;
+
+    if !response.ok() {
+        return Err(format!("HTTP error: {}", response.status()));
+    }
+
+    response.json().await.map_err(|e| e.to_string())
+}
+
 // ========== Songs API ==========
 
 /// Fetches all songbooks.
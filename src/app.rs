@@ -9,10 +9,12 @@ use leptos_router::{
 use revelation_ui::{BrowserChrome, THEME_CSS, ThemeProvider, ToastProvider};
 
 use crate::{
-    components::Sidebar,
+    components::{Shortcuts, Sidebar},
     pages::{
-        Bible, BibleChapter, ChurchDetail, Churches, DailyReading, Feed, NotFound, Onboarding,
-        Profile, Search, Settings, SongDetail, SongSearch, SongbookDetail, SongbookSongs, Songs
+        Bible, BibleChapter, BookOverview, ChurchDetail, Churches, DailyReading, Dictionary,
+        DictionaryArticle, Feed, Harmony, MapView, Maps, Memorize, NotFound, Onboarding,
+        PlanDetail, Present, PresentScreen, PrintPassage, Profile, ReadingPlans, Search, Settings,
+        SongDetail, SongSearch, SongbookDetail, SongbookSongs, Songs, Stats, Topics
     },
     state::AppState
 };
@@ -50,6 +52,7 @@ pub fn App() -> impl IntoView {
             <ChromeSync/>
             <ToastProvider>
                 <Router>
+                    <Shortcuts/>
                     <div class="flex">
                         // Desktop sidebar
                         <Sidebar/>
@@ -61,9 +64,22 @@ pub fn App() -> impl IntoView {
                                 <Route path=path!("/onboarding") view=Onboarding/>
                                 <Route path=path!("/feed") view=Feed/>
                                 <Route path=path!("/bible") view=Bible/>
+                                <Route path=path!("/bible/:book") view=BookOverview/>
                                 <Route path=path!("/bible/:book/:chapter") view=BibleChapter/>
                                 <Route path=path!("/search") view=Search/>
                                 <Route path=path!("/today") view=DailyReading/>
+                                <Route path=path!("/plans") view=ReadingPlans/>
+                                <Route path=path!("/plans/:id") view=PlanDetail/>
+                                <Route path=path!("/memorize") view=Memorize/>
+                                <Route path=path!("/dictionary") view=Dictionary/>
+                                <Route path=path!("/dictionary/:id") view=DictionaryArticle/>
+                                <Route path=path!("/topics") view=Topics/>
+                                <Route path=path!("/harmony") view=Harmony/>
+                                <Route path=path!("/maps") view=Maps/>
+                                <Route path=path!("/maps/:id") view=MapView/>
+                                <Route path=path!("/present") view=Present/>
+                                <Route path=path!("/present/screen") view=PresentScreen/>
+                                <Route path=path!("/print") view=PrintPassage/>
                                 <Route path=path!("/churches") view=Churches/>
                                 <Route path=path!("/church/:id") view=ChurchDetail/>
                                 <Route path=path!("/songs") view=Songs/>
@@ -73,6 +89,7 @@ pub fn App() -> impl IntoView {
                                 <Route path=path!("/songs/:id") view=SongDetail/>
                                 <Route path=path!("/profile") view=Profile/>
                                 <Route path=path!("/settings") view=Settings/>
+                                <Route path=path!("/stats") view=Stats/>
                             </Routes>
                         </div>
                     </div>
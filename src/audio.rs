@@ -0,0 +1,251 @@
+//! Recorded Synodal audio - chapter files, verse timings and offline
+//! downloads.
+
+use gloo_net::http::Request;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{closure::Closure, prelude::*};
+
+use crate::bible::{book_code, book_name};
+
+#[cfg(debug_assertions)]
+const AUDIO_URL: &str = "/audio/synodal";
+
+#[cfg(not(debug_assertions))]
+const AUDIO_URL: &str = "https://s3.twcstorage.ru/7f594bdf-revelation/audio/synodal";
+/// Cache Storage bucket for downloaded chapters (kept by `sw.js`).
+const AUDIO_CACHE: &str = "revelation-audio";
+const DOWNLOADS_KEY: &str = "audio_downloads";
+
+#[wasm_bindgen(inline_js = "
+export async function cacheUrls(name, urls) {
+    const cache = await caches.open(name);
+    await cache.addAll(urls);
+}
+
+export async function deleteCache(name) {
+    await caches.delete(name);
+}
+
+export function setMediaMetadata(title, artist) {
+    if (!('mediaSession' in navigator)) {
+        return;
+    }
+    navigator.mediaSession.metadata = new MediaMetadata({
+        title,
+        artist,
+        album: 'Синодальный перевод',
+        artwork: [
+            { src: '/icons/icon-192.png', sizes: '192x192', type: 'image/png' },
+            { src: '/icons/icon-512.png', sizes: '512x512', type: 'image/png' }
+        ]
+    });
+}
+
+export function setMediaHandler(action, handler) {
+    if (!('mediaSession' in navigator)) {
+        return;
+    }
+    try {
+        navigator.mediaSession.setActionHandler(action, handler);
+    } catch (e) {
+        // Action not supported by this browser
+    }
+}
+
+export function setMediaPlaying(playing) {
+    if ('mediaSession' in navigator) {
+        navigator.mediaSession.playbackState = playing ? 'playing' : 'paused';
+    }
+}
+")]
+extern "C" {
+    #[wasm_bindgen(catch)]
+    async fn cacheUrls(name: &str, urls: Vec<String>) -> Result<(), JsValue>;
+    #[wasm_bindgen(catch)]
+    async fn deleteCache(name: &str) -> Result<(), JsValue>;
+    fn setMediaMetadata(title: &str, artist: &str);
+    fn setMediaHandler(action: &str, handler: JsValue);
+    fn setMediaPlaying(playing: bool);
+}
+
+/// Where listen mode takes its voice from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ListenSource {
+    /// Recorded audio with verse timings.
+    #[default]
+    Audio,
+    /// Browser speech synthesis.
+    Speech
+}
+
+/// Start of a verse within the chapter recording.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VerseTiming {
+    /// Verse number.
+    pub verse: i16,
+    /// Offset from the start of the recording, in seconds.
+    pub start: f64
+}
+
+/// Lock-screen action forwarded from the Media Session API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaAction {
+    /// Resume playback.
+    Play,
+    /// Pause playback.
+    Pause,
+    /// Previous verse.
+    Previous,
+    /// Next verse.
+    Next
+}
+
+impl MediaAction {
+    const ALL: [Self; 4] = [Self::Play, Self::Pause, Self::Previous, Self::Next];
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Play => "play",
+            Self::Pause => "pause",
+            Self::Previous => "previoustrack",
+            Self::Next => "nexttrack"
+        }
+    }
+}
+
+/// Returns the recording URL for a chapter.
+#[must_use]
+pub fn audio_url(book_id: i16, chapter: i16) -> Option<String> {
+    Some(format!("{AUDIO_URL}/{}/{chapter}.mp3", book_code(book_id)?))
+}
+
+/// Returns the verse timing URL for a chapter.
+#[must_use]
+pub fn timing_url(book_id: i16, chapter: i16) -> Option<String> {
+    Some(format!(
+        "{AUDIO_URL}/{}/{chapter}.json",
+        book_code(book_id)?
+    ))
+}
+
+/// Fetches verse timings for a chapter.
+///
+/// # Errors
+///
+/// Returns error string if network or parsing fails.
+pub async fn fetch_timings(book_id: i16, chapter: i16) -> Result<Vec<VerseTiming>, String> {
+    let url = timing_url(book_id, chapter).ok_or("Unknown book")?;
+    let response = Request::get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Network error: {e}"))?;
+
+    if !response.ok() {
+        return Err(format!("HTTP error: {}", response.status()));
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| format!("Parse error: {e}"))
+}
+
+/// Returns the verse being read at `time` seconds.
+#[must_use]
+pub fn verse_at(timings: &[VerseTiming], time: f64) -> Option<i16> {
+    timings
+        .iter()
+        .take_while(|t| t.start <= time)
+        .last()
+        .map(|t| t.verse)
+}
+
+/// Returns the offset of a verse in the recording.
+#[must_use]
+pub fn verse_start(timings: &[VerseTiming], verse: i16) -> Option<f64> {
+    timings.iter().find(|t| t.verse == verse).map(|t| t.start)
+}
+
+/// Publishes the chapter on the lock screen and routes its buttons to
+/// `on_action`.
+pub fn register_media_session(
+    book_id: i16,
+    chapter: i16,
+    on_action: impl Fn(MediaAction) + Clone + 'static
+) {
+    let title = format!("{} {chapter}", book_name(book_id).unwrap_or_default());
+    setMediaMetadata(&title, "Библия");
+
+    for action in MediaAction::ALL {
+        let on_action = on_action.clone();
+        let handler = Closure::<dyn Fn()>::new(move || on_action(action));
+        setMediaHandler(action.name(), handler.into_js_value());
+    }
+}
+
+/// Removes lock-screen handlers.
+pub fn clear_media_session() {
+    for action in MediaAction::ALL {
+        setMediaHandler(action.name(), JsValue::NULL);
+    }
+    setMediaPlaying(false);
+}
+
+/// Updates the lock-screen playback state.
+pub fn set_media_playing(playing: bool) {
+    setMediaPlaying(playing);
+}
+
+/// Chapters saved for offline listening.
+pub struct AudioDownloads;
+
+impl AudioDownloads {
+    /// Returns downloaded chapters as `(book_id, chapter)` pairs.
+    #[must_use]
+    pub fn list() -> Vec<(i16, i16)> {
+        LocalStorage::get(DOWNLOADS_KEY).unwrap_or_default()
+    }
+
+    /// Checks whether a chapter is available offline.
+    #[must_use]
+    pub fn contains(book_id: i16, chapter: i16) -> bool {
+        Self::list().contains(&(book_id, chapter))
+    }
+
+    /// Downloads chapter audio and timings into the offline cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns error string if the files cannot be fetched or stored.
+    pub async fn download(book_id: i16, chapter: i16) -> Result<(), String> {
+        let urls = audio_url(book_id, chapter)
+            .zip(timing_url(book_id, chapter))
+            .ok_or("Unknown book")?;
+
+        cacheUrls(AUDIO_CACHE, vec![urls.0, urls.1])
+            .await
+            .map_err(|e| format!("Download error: {e:?}"))?;
+
+        let mut list = Self::list();
+        if !list.contains(&(book_id, chapter)) {
+            list.push((book_id, chapter));
+            let _ = LocalStorage::set(DOWNLOADS_KEY, list);
+        }
+
+        Ok(())
+    }
+
+    /// Deletes all downloaded chapters.
+    ///
+    /// # Errors
+    ///
+    /// Returns error string if the cache cannot be deleted.
+    pub async fn clear() -> Result<(), String> {
+        deleteCache(AUDIO_CACHE)
+            .await
+            .map_err(|e| format!("Cache error: {e:?}"))?;
+        LocalStorage::delete(DOWNLOADS_KEY);
+        Ok(())
+    }
+}
@@ -8,11 +8,22 @@ use revelation_bible::{Book, Testament, Verse};
 use serde::{Deserialize, Serialize};
 use wasm_bindgen_futures::spawn_local;
 
+use crate::{
+    reference::Reference,
+    versification::{VerseKey, Versification}
+};
+
 #[cfg(debug_assertions)]
 const BIBLE_URL: &str = "/bible/synodal.json";
 
 #[cfg(not(debug_assertions))]
 const BIBLE_URL: &str = "https://s3.twcstorage.ru/7f594bdf-revelation/synodal.json";
+
+#[cfg(debug_assertions)]
+const TRANSLATIONS_URL: &str = "/bible";
+
+#[cfg(not(debug_assertions))]
+const TRANSLATIONS_URL: &str = "https://s3.twcstorage.ru/7f594bdf-revelation";
 const CACHE_KEY: &str = "bible_synodal";
 const CACHE_VERSION_KEY: &str = "bible_version";
 const CURRENT_VERSION: &str = "1.0.0";
@@ -166,6 +177,44 @@ const ABBREVIATIONS: [&str; 66] = [
     "Откр"
 ];
 
+/// Get book category CSS variable.
+#[must_use]
+pub const fn get_book_category_var(book_id: i16) -> &'static str {
+    match book_id {
+        6..=17 => "var(--cat-history)",
+        18..=22 => "var(--cat-wisdom)",
+        23..=27 => "var(--cat-major-prophets)",
+        28..=39 => "var(--cat-minor-prophets)",
+        40..=43 => "var(--cat-gospels)",
+        44 => "var(--cat-acts)",
+        45..=57 => "var(--cat-paul)",
+        58..=65 => "var(--cat-general)",
+        66 => "var(--cat-revelation)",
+        _ => "var(--cat-torah)"
+    }
+}
+
+/// Returns the Russian name of a book (1-66).
+#[must_use]
+pub fn book_name(book_id: i16) -> Option<&'static str> {
+    let idx = usize::try_from(book_id).ok()?.checked_sub(1)?;
+    BOOK_NAMES_RU.get(idx).copied()
+}
+
+/// Returns the Russian abbreviation of a book (1-66).
+#[must_use]
+pub fn book_abbreviation(book_id: i16) -> Option<&'static str> {
+    let idx = usize::try_from(book_id).ok()?.checked_sub(1)?;
+    ABBREVIATIONS.get(idx).copied()
+}
+
+/// Returns the English source abbreviation of a book (1-66).
+#[must_use]
+pub fn book_code(book_id: i16) -> Option<&'static str> {
+    let idx = usize::try_from(book_id).ok()?.checked_sub(1)?;
+    ABBREV_ORDER.get(idx).copied()
+}
+
 /// Raw Bible data from S3 JSON.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RawBook {
@@ -175,6 +224,59 @@ pub struct RawBook {
     pub chapters: Vec<Vec<String>>
 }
 
+/// Bible translation available for reading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Translation {
+    /// Russian Synodal, the main text of the app.
+    Synodal,
+    /// King James Version.
+    Kjv,
+    /// American Standard Version.
+    Asv
+}
+
+impl Translation {
+    /// Translations that can be shown next to the Synodal text.
+    pub const PARALLEL: [Self; 2] = [Self::Kjv, Self::Asv];
+
+    /// Short label.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Synodal => "СП",
+            Self::Kjv => "KJV",
+            Self::Asv => "ASV"
+        }
+    }
+
+    /// Full name.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Synodal => "Синодальный перевод",
+            Self::Kjv => "King James Version",
+            Self::Asv => "American Standard Version"
+        }
+    }
+
+    /// Verse numbering of the translation.
+    #[must_use]
+    pub const fn versification(self) -> Versification {
+        match self {
+            Self::Synodal => Versification::Synodal,
+            Self::Kjv | Self::Asv => Versification::Kjv
+        }
+    }
+
+    const fn code(self) -> &'static str {
+        match self {
+            Self::Synodal => "synodal",
+            Self::Kjv => "kjv",
+            Self::Asv => "asv"
+        }
+    }
+}
+
 /// Cached Bible with indexed access.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct BibleCache {
@@ -230,6 +332,70 @@ impl BibleCache {
         )
     }
 
+    /// Returns the text of a single verse.
+    #[must_use]
+    pub fn get_verse_text(&self, book_id: i16, chapter: i16, verse: i16) -> Option<&str> {
+        let book = self.get_book(book_id)?;
+        let chapter = book.chapters.get(usize::try_from(chapter - 1).ok()?)?;
+        chapter
+            .get(usize::try_from(verse - 1).ok()?)
+            .map(String::as_str)
+    }
+
+    /// Iterates over every verse of the Bible in canonical order.
+    pub fn verses(&self) -> impl Iterator<Item = (VerseKey, &str)> {
+        self.books.iter().zip(1_i16..).flat_map(|(book, book_id)| {
+            book.chapters
+                .iter()
+                .zip(1_i16..)
+                .flat_map(move |(verses, chapter)| {
+                    verses
+                        .iter()
+                        .zip(1_i16..)
+                        .map(move |(text, verse)| ((book_id, chapter, verse), text.as_str()))
+                })
+        })
+    }
+
+    /// Returns the verses of a passage, possibly spanning several chapters.
+    #[must_use]
+    pub fn get_passage(&self, reference: &Reference) -> Vec<Verse> {
+        (reference.chapter..=reference.end_chapter)
+            .filter_map(|chapter| self.get_chapter(reference.book_id, chapter))
+            .flatten()
+            .filter(|v| reference.contains(v.chapter, v.verse))
+            .collect()
+    }
+
+    /// Checks that a reference stays within the chapters and verses of this
+    /// translation.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message naming the exceeded bound.
+    pub fn check_reference(&self, reference: &Reference) -> Result<(), String> {
+        let name = book_name(reference.book_id).unwrap_or_default();
+        let book = self
+            .get_book(reference.book_id)
+            .ok_or_else(|| format!("Книги «{name}» нет в этом переводе"))?;
+        let points = [
+            (reference.chapter, reference.verse),
+            (reference.end_chapter, reference.end_verse)
+        ];
+        for (chapter, verse) in points {
+            let verses = usize::try_from(chapter - 1)
+                .ok()
+                .and_then(|idx| book.chapters.get(idx))
+                .ok_or_else(|| format!("В книге «{name}» глав: {}", book.chapters.len()))?;
+            if let Some(verse) = verse
+                && usize::try_from(verse).map_or(true, |v| v > verses.len())
+            {
+                return Err(format!("В главе {chapter} стихов: {}", verses.len()));
+            }
+        }
+        Ok(())
+    }
+
     /// Returns all books with metadata.
     #[must_use]
     pub fn get_books(&self) -> Vec<Book> {
@@ -258,6 +424,49 @@ impl BibleCache {
     }
 }
 
+/// Direction of chapter navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChapterStep {
+    /// Previous chapter.
+    Prev,
+    /// Next chapter.
+    Next
+}
+
+/// Returns the chapter adjacent to `(book_id, chapter)` in canonical order.
+///
+/// Crosses book boundaries: stepping back from chapter 1 lands on the last
+/// chapter of the previous book, stepping forward from the last chapter lands
+/// on chapter 1 of the next one. Returns `None` at the ends of the Bible.
+#[must_use]
+pub fn adjacent_chapter(
+    books: &[Book],
+    book_id: i16,
+    chapter: i16,
+    step: ChapterStep
+) -> Option<(i16, i16)> {
+    match step {
+        ChapterStep::Prev => {
+            if chapter > 1 {
+                return Some((book_id, chapter - 1));
+            }
+            let prev = books.iter().find(|b| b.id == book_id - 1)?;
+            Some((prev.id, prev.chapters_count))
+        }
+        ChapterStep::Next => {
+            let max_chapter = books
+                .iter()
+                .find(|b| b.id == book_id)
+                .map_or(1, |b| b.chapters_count);
+            if chapter < max_chapter {
+                return Some((book_id, chapter + 1));
+            }
+            let next = books.iter().find(|b| b.id == book_id + 1)?;
+            Some((next.id, 1))
+        }
+    }
+}
+
 /// Bible data provider with caching.
 pub struct BibleProvider;
 
@@ -298,6 +507,21 @@ impl BibleProvider {
         Self::fetch_from_url(BIBLE_URL).await
     }
 
+    /// Fetches another translation for the parallel view.
+    ///
+    /// Only the Synodal text is kept in `LocalStorage`; other translations
+    /// are loaded on demand.
+    ///
+    /// # Errors
+    ///
+    /// Returns error string if network or parsing fails.
+    pub async fn fetch_translation(translation: Translation) -> Result<BibleCache, String> {
+        if translation == Translation::Synodal {
+            return Self::init().await;
+        }
+        Self::fetch_from_url(&format!("{TRANSLATIONS_URL}/{}.json", translation.code())).await
+    }
+
     /// Fetches from a specific URL.
     async fn fetch_from_url(url: &str) -> Result<BibleCache, String> {
         let response = Request::get(url)
@@ -0,0 +1,88 @@
+//! Book introductions - authorship, dating and reading time.
+
+use std::sync::OnceLock;
+
+use revelation_bible::Pericope;
+use serde::{Deserialize, Serialize};
+
+use crate::{bible::BibleCache, search::words};
+
+/// Introductions shipped with the app.
+const BUNDLED_INTROS: &str = include_str!("../assets/book_intros.json");
+
+/// Average silent reading speed, words per minute.
+const WORDS_PER_MINUTE: usize = 150;
+
+/// Authorship and dating of a book.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookIntro {
+    /// Author by tradition or scholarship.
+    pub author: String,
+    /// Time of writing.
+    pub date:   String
+}
+
+#[derive(Deserialize)]
+struct IntrosFile {
+    books: Vec<BookIntro>
+}
+
+/// Returns the introduction of a book.
+///
+/// # Panics
+///
+/// Panics if the bundled `book_intros.json` is malformed.
+#[must_use]
+pub fn book_intro(book_id: i16) -> Option<&'static BookIntro> {
+    static INTROS: OnceLock<Vec<BookIntro>> = OnceLock::new();
+    let intros = INTROS.get_or_init(|| {
+        serde_json::from_str::<IntrosFile>(BUNDLED_INTROS)
+            .expect("assets/book_intros.json is malformed")
+            .books
+    });
+    let idx = usize::try_from(book_id).ok()?.checked_sub(1)?;
+    intros.get(idx)
+}
+
+/// Estimated minutes to read a whole book, at least one.
+#[must_use]
+pub fn reading_minutes(bible: &BibleCache, book_id: i16, chapters: i16) -> usize {
+    let word_count: usize = (1..=chapters)
+        .filter_map(|chapter| bible.get_chapter(book_id, chapter))
+        .flatten()
+        .map(|v| words(&v.text).count())
+        .sum();
+    word_count.div_ceil(WORDS_PER_MINUTE).max(1)
+}
+
+/// Pericope headings grouped by chapter, in order.
+#[must_use]
+pub fn outline(pericopes: &[Pericope]) -> Vec<(i16, Vec<Pericope>)> {
+    let mut sorted = pericopes.to_vec();
+    sorted.sort_by_key(|p| (p.chapter, p.verse));
+    let mut groups: Vec<(i16, Vec<Pericope>)> = Vec::new();
+    for pericope in sorted {
+        match groups.last_mut() {
+            Some((chapter, items)) if *chapter == pericope.chapter => items.push(pericope),
+            _ => groups.push((pericope.chapter, vec![pericope]))
+        }
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_intros_cover_every_book() {
+        for book_id in 1..=66 {
+            let intro = book_intro(book_id).unwrap_or_else(|| panic!("book {book_id}"));
+            assert!(
+                !intro.author.is_empty() && !intro.date.is_empty(),
+                "book {book_id}"
+            );
+        }
+        assert!(book_intro(67).is_none());
+    }
+}
@@ -0,0 +1,890 @@
+//! Orthodox liturgical calendar - Paschalion, moveable feasts and the
+//! Apostol/Gospel lectionary.
+//!
+//! Pascha is always computed on the Julian Paschalion. The calendar style
+//! only moves the fixed feasts: on the Julian calendar they fall 13 days
+//! later in civil dates, on the Revised Julian they match the civil date.
+//!
+//! Sundays and feasts are kept in the tables below; the weekday readings of
+//! the Triodion, the Pentecostarion and the weeks after Pentecost are
+//! bundled in `lectionary.json`. Weekdays of Great Lent have no Liturgy and
+//! therefore no readings.
+//!
+//! After Pentecost the Apostol follows the week count, while the Gospel
+//! jumps to Luke after the Sunday after the Exaltation. From the Sunday
+//! after Theophany both count back from the Sunday of Zacchaeus, repeating
+//! weeks in a short year (otstupka) and skipping them in a long one
+//! (prestupka).
+
+use std::sync::OnceLock;
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::reference::{Reference, parse_references};
+
+const BUNDLED_LECTIONARY: &str = include_str!("../assets/lectionary.json");
+
+/// Days between 0001-01-01 (Common Era day 1) and Julian Day Number 0.
+const JDN_CE_OFFSET: i64 = 1_721_425;
+
+/// Weekday names from Monday to Saturday, as used in day titles.
+const WEEKDAYS: [&str; 6] = [
+    "Понедельник",
+    "Вторник",
+    "Среда",
+    "Четверг",
+    "Пятница",
+    "Суббота"
+];
+
+/// Calendar used for fixed feasts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CalendarStyle {
+    /// Old style, used by the Russian, Serbian and Georgian churches.
+    #[default]
+    Julian,
+    /// New style, used by Constantinople, Greece, Romania and others.
+    RevisedJulian
+}
+
+impl CalendarStyle {
+    /// All styles in display order.
+    pub const ALL: [Self; 2] = [Self::Julian, Self::RevisedJulian];
+
+    /// Display label.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Julian => "Старый стиль",
+            Self::RevisedJulian => "Новый стиль"
+        }
+    }
+}
+
+/// Readings appointed for one service.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lection {
+    /// Day or feast name.
+    pub title:   String,
+    /// Epistle readings.
+    pub apostol: Vec<Reference>,
+    /// Gospel readings.
+    pub gospel:  Vec<Reference>
+}
+
+/// Readings of one weekday before parsing.
+#[derive(Debug, Deserialize)]
+struct Readings {
+    apostol: Vec<String>,
+    gospel:  Vec<String>
+}
+
+impl Readings {
+    /// Parses the readings under a title.
+    fn lection(&self, title: String) -> Lection {
+        Lection {
+            title,
+            apostol: parse_references(&self.apostol),
+            gospel: parse_references(&self.gospel)
+        }
+    }
+}
+
+/// Weekday of the Triodion or Pentecostarion.
+#[derive(Debug, Deserialize)]
+struct PaschalDay {
+    /// Days from Pascha.
+    day:      i64,
+    title:    String,
+    #[serde(flatten)]
+    readings: Readings
+}
+
+/// Monday to Saturday of one reading week after Pentecost.
+#[derive(Debug, Deserialize)]
+struct ReadingWeek {
+    week: usize,
+    days: [Readings; 6]
+}
+
+/// Weekday lectionary shipped with the app.
+#[derive(Debug, Deserialize)]
+struct Lectionary {
+    paschal: Vec<PaschalDay>,
+    weeks:   Vec<ReadingWeek>
+}
+
+/// Returns the bundled weekday lectionary, parsed on first use.
+fn lectionary() -> &'static Lectionary {
+    static LECTIONARY: OnceLock<Lectionary> = OnceLock::new();
+    LECTIONARY.get_or_init(|| {
+        serde_json::from_str(BUNDLED_LECTIONARY).expect("assets/lectionary.json is malformed")
+    })
+}
+
+/// Lectionary entry before parsing.
+type Entry = (
+    &'static str,
+    &'static [&'static str],
+    &'static [&'static str]
+);
+
+/// Triodion and Pentecostarion, keyed by days from Pascha.
+const PASCHAL_CYCLE: [(i64, Entry); 32] = [
+    (
+        -70,
+        (
+            "Неделя о мытаре и фарисее",
+            &["2Тим 3:10-15"],
+            &["Лк 18:10-14"]
+        )
+    ),
+    (
+        -63,
+        ("Неделя о блудном сыне", &["1Кор 6:12-20"], &["Лк 15:11-32"])
+    ),
+    (
+        -56,
+        (
+            "Неделя мясопустная, о Страшном суде",
+            &["1Кор 8:8-9:2"],
+            &["Мф 25:31-46"]
+        )
+    ),
+    (
+        -49,
+        (
+            "Неделя сыропустная, Прощёное воскресенье",
+            &["Рим 13:11-14:4"],
+            &["Мф 6:14-21"]
+        )
+    ),
+    (
+        -42,
+        (
+            "Неделя 1-я Великого поста, Торжество Православия",
+            &["Евр 11:24-26", "Евр 11:32-12:2"],
+            &["Ин 1:43-51"]
+        )
+    ),
+    (
+        -35,
+        (
+            "Неделя 2-я Великого поста, свт. Григория Паламы",
+            &["Евр 1:10-2:3"],
+            &["Мк 2:1-12"]
+        )
+    ),
+    (
+        -28,
+        (
+            "Неделя 3-я Великого поста, Крестопоклонная",
+            &["Евр 4:14-5:6"],
+            &["Мк 8:34-9:1"]
+        )
+    ),
+    (
+        -21,
+        (
+            "Неделя 4-я Великого поста, прп. Иоанна Лествичника",
+            &["Евр 6:13-20"],
+            &["Мк 9:17-31"]
+        )
+    ),
+    (
+        -14,
+        (
+            "Неделя 5-я Великого поста, прп. Марии Египетской",
+            &["Евр 9:11-14"],
+            &["Мк 10:32-45"]
+        )
+    ),
+    (
+        -8,
+        ("Лазарева суббота", &["Евр 12:28-13:8"], &["Ин 11:1-45"])
+    ),
+    (
+        -7,
+        (
+            "Вход Господень в Иерусалим",
+            &["Флп 4:4-9"],
+            &["Ин 12:1-18"]
+        )
+    ),
+    (
+        -3,
+        (
+            "Великий Четверг",
+            &["1Кор 11:23-32"],
+            &[
+                "Мф 26:1-20",
+                "Ин 13:3-17",
+                "Мф 26:21-39",
+                "Лк 22:43-45",
+                "Мф 26:40-27:2"
+            ]
+        )
+    ),
+    (
+        -2,
+        (
+            "Великая Пятница",
+            &["1Кор 1:18-2:2"],
+            &[
+                "Мф 27:1-38",
+                "Лк 23:39-43",
+                "Мф 27:39-54",
+                "Ин 19:31-37",
+                "Мф 27:55-61"
+            ]
+        )
+    ),
+    (-1, ("Великая Суббота", &["Рим 6:3-11"], &["Мф 28:1-20"])),
+    (
+        0,
+        (
+            "Светлое Христово Воскресение",
+            &["Деян 1:1-8"],
+            &["Ин 1:1-17"]
+        )
+    ),
+    (
+        1,
+        (
+            "Понедельник Светлой седмицы",
+            &["Деян 1:12-17", "Деян 1:21-26"],
+            &["Ин 1:18-28"]
+        )
+    ),
+    (
+        2,
+        (
+            "Вторник Светлой седмицы",
+            &["Деян 2:14-21"],
+            &["Лк 24:12-35"]
+        )
+    ),
+    (
+        3,
+        ("Среда Светлой седмицы", &["Деян 2:22-36"], &["Ин 1:35-51"])
+    ),
+    (
+        4,
+        ("Четверг Светлой седмицы", &["Деян 2:38-43"], &["Ин 3:1-15"])
+    ),
+    (
+        5,
+        ("Пятница Светлой седмицы", &["Деян 3:1-8"], &["Ин 2:12-22"])
+    ),
+    (
+        6,
+        (
+            "Суббота Светлой седмицы",
+            &["Деян 3:11-16"],
+            &["Ин 3:22-33"]
+        )
+    ),
+    (
+        7,
+        (
+            "Неделя 2-я по Пасхе, апостола Фомы",
+            &["Деян 5:12-20"],
+            &["Ин 20:19-31"]
+        )
+    ),
+    (
+        14,
+        (
+            "Неделя 3-я по Пасхе, святых жен-мироносиц",
+            &["Деян 6:1-7"],
+            &["Мк 15:43-16:8"]
+        )
+    ),
+    (
+        21,
+        (
+            "Неделя 4-я по Пасхе, о расслабленном",
+            &["Деян 9:32-42"],
+            &["Ин 5:1-15"]
+        )
+    ),
+    (
+        24,
+        (
+            "Преполовение Пятидесятницы",
+            &["Деян 14:6-18"],
+            &["Ин 7:14-30"]
+        )
+    ),
+    (
+        28,
+        (
+            "Неделя 5-я по Пасхе, о самаряныне",
+            &["Деян 11:19-26", "Деян 11:29-30"],
+            &["Ин 4:5-42"]
+        )
+    ),
+    (
+        35,
+        (
+            "Неделя 6-я по Пасхе, о слепом",
+            &["Деян 16:16-34"],
+            &["Ин 9:1-38"]
+        )
+    ),
+    (
+        39,
+        ("Вознесение Господне", &["Деян 1:1-12"], &["Лк 24:36-53"])
+    ),
+    (
+        42,
+        (
+            "Неделя 7-я по Пасхе, святых отцов I Вселенского собора",
+            &["Деян 20:16-18", "Деян 20:28-36"],
+            &["Ин 17:1-13"]
+        )
+    ),
+    (
+        49,
+        (
+            "День Святой Троицы, Пятидесятница",
+            &["Деян 2:1-11"],
+            &["Ин 7:37-52", "Ин 8:12"]
+        )
+    ),
+    (50, ("День Святого Духа", &["Еф 5:9-19"], &["Мф 18:10-20"])),
+    (
+        56,
+        (
+            "Неделя 1-я по Пятидесятнице, Всех святых",
+            &["Евр 11:33-12:2"],
+            &["Мф 10:32-33", "Мф 10:37-38", "Мф 19:27-30"]
+        )
+    )
+];
+
+/// Great fixed feasts, keyed by church calendar month and day.
+const FIXED_FEASTS: [(u32, u32, Entry); 14] = [
+    (
+        1,
+        1,
+        (
+            "Обрезание Господне, свт. Василия Великого",
+            &["Кол 2:8-12"],
+            &["Лк 2:20-21", "Лк 2:40-52"]
+        )
+    ),
+    (
+        1,
+        6,
+        (
+            "Крещение Господне, Богоявление",
+            &["Тит 2:11-14", "Тит 3:4-7"],
+            &["Мф 3:13-17"]
+        )
+    ),
+    (
+        2,
+        2,
+        ("Сретение Господне", &["Евр 7:7-17"], &["Лк 2:22-40"])
+    ),
+    (
+        3,
+        25,
+        (
+            "Благовещение Пресвятой Богородицы",
+            &["Евр 2:11-18"],
+            &["Лк 1:24-38"]
+        )
+    ),
+    (
+        6,
+        24,
+        (
+            "Рождество Иоанна Предтечи",
+            &["Рим 13:11-14:4"],
+            &["Лк 1:1-25", "Лк 1:57-68", "Лк 1:76", "Лк 1:80"]
+        )
+    ),
+    (
+        6,
+        29,
+        (
+            "Первоверховных апостолов Петра и Павла",
+            &["2Кор 11:21-12:9"],
+            &["Мф 16:13-19"]
+        )
+    ),
+    (
+        8,
+        6,
+        ("Преображение Господне", &["2Пет 1:10-19"], &["Мф 17:1-9"])
+    ),
+    (
+        8,
+        15,
+        (
+            "Успение Пресвятой Богородицы",
+            &["Флп 2:5-11"],
+            &["Лк 10:38-42", "Лк 11:27-28"]
+        )
+    ),
+    (
+        8,
+        29,
+        (
+            "Усекновение главы Иоанна Предтечи",
+            &["Деян 13:25-32"],
+            &["Мк 6:14-30"]
+        )
+    ),
+    (
+        9,
+        8,
+        (
+            "Рождество Пресвятой Богородицы",
+            &["Флп 2:5-11"],
+            &["Лк 10:38-42", "Лк 11:27-28"]
+        )
+    ),
+    (
+        9,
+        14,
+        (
+            "Воздвижение Креста Господня",
+            &["1Кор 1:18-24"],
+            &["Ин 19:6-11", "Ин 19:13-20", "Ин 19:25-28", "Ин 19:30-35"]
+        )
+    ),
+    (
+        10,
+        1,
+        (
+            "Покров Пресвятой Богородицы",
+            &["Евр 9:1-7"],
+            &["Лк 10:38-42", "Лк 11:27-28"]
+        )
+    ),
+    (
+        11,
+        21,
+        (
+            "Введение во храм Пресвятой Богородицы",
+            &["Евр 9:1-7"],
+            &["Лк 10:38-42", "Лк 11:27-28"]
+        )
+    ),
+    (
+        12,
+        25,
+        ("Рождество Христово", &["Гал 4:4-7"], &["Мф 2:1-12"])
+    )
+];
+
+/// Sundays with proper readings, keyed by the church calendar month and
+/// the first and last day they can fall on.
+const FIXED_SUNDAYS: [(u32, u32, u32, Entry); 8] = [
+    (
+        9,
+        7,
+        13,
+        (
+            "Неделя перед Воздвижением",
+            &["Гал 6:11-18"],
+            &["Ин 3:13-17"]
+        )
+    ),
+    (
+        9,
+        15,
+        21,
+        ("Неделя по Воздвижении", &["Гал 2:16-20"], &["Мк 8:34-9:1"])
+    ),
+    (
+        10,
+        11,
+        17,
+        (
+            "Неделя святых отцов VII Вселенского Собора",
+            &["Евр 13:7-16"],
+            &["Ин 17:1-13"]
+        )
+    ),
+    (
+        12,
+        11,
+        17,
+        ("Неделя святых праотец", &["Кол 3:4-11"], &["Лк 14:16-24"])
+    ),
+    (
+        12,
+        18,
+        24,
+        (
+            "Неделя святых отец, перед Рождеством Христовым",
+            &["Евр 11:9-10", "Евр 11:17-23", "Евр 11:32-40"],
+            &["Мф 1:1-25"]
+        )
+    ),
+    (
+        12,
+        26,
+        31,
+        (
+            "Неделя по Рождестве Христовом",
+            &["Гал 1:11-19"],
+            &["Мф 2:13-23"]
+        )
+    ),
+    (
+        1,
+        1,
+        5,
+        ("Неделя перед Богоявлением", &["2Тим 4:5-8"], &["Мк 1:1-8"])
+    ),
+    (
+        1,
+        7,
+        13,
+        ("Неделя по Богоявлении", &["Еф 4:7-13"], &["Мф 4:12-17"])
+    )
+];
+
+/// Sunday Epistles after Pentecost, from the 2nd Sunday.
+const SUNDAY_APOSTOL: [&str; 31] = [
+    "Рим 2:10-16",
+    "Рим 5:1-10",
+    "Рим 6:18-23",
+    "Рим 10:1-10",
+    "Рим 12:6-14",
+    "Рим 15:1-7",
+    "1Кор 1:10-18",
+    "1Кор 3:9-17",
+    "1Кор 4:9-16",
+    "1Кор 9:2-12",
+    "1Кор 15:1-11",
+    "1Кор 16:13-24",
+    "2Кор 1:21-2:4",
+    "2Кор 4:6-15",
+    "2Кор 6:1-10",
+    "2Кор 6:16-7:1",
+    "2Кор 9:6-11",
+    "2Кор 11:31-12:9",
+    "Гал 1:11-19",
+    "Гал 2:16-20",
+    "Гал 6:11-18",
+    "Еф 2:4-10",
+    "Еф 2:14-22",
+    "Еф 4:1-6",
+    "Еф 5:9-19",
+    "Еф 6:10-17",
+    "Кол 1:12-18",
+    "Кол 3:4-11",
+    "Кол 3:12-16",
+    "1Тим 1:15-17",
+    "1Тим 4:9-15"
+];
+
+/// Sunday Gospels after Pentecost, from the 2nd Sunday: Matthew up to the
+/// 17th, then Luke after the Exaltation.
+const SUNDAY_GOSPEL: [&str; 31] = [
+    "Мф 4:18-23",
+    "Мф 6:22-33",
+    "Мф 8:5-13",
+    "Мф 8:28-9:1",
+    "Мф 9:1-8",
+    "Мф 9:27-35",
+    "Мф 14:14-22",
+    "Мф 14:22-34",
+    "Мф 17:14-23",
+    "Мф 18:23-35",
+    "Мф 19:16-26",
+    "Мф 21:33-42",
+    "Мф 22:1-14",
+    "Мф 22:35-46",
+    "Мф 25:14-30",
+    "Мф 15:21-28",
+    "Лк 5:1-11",
+    "Лк 6:31-36",
+    "Лк 7:11-16",
+    "Лк 8:5-15",
+    "Лк 16:19-31",
+    "Лк 8:26-39",
+    "Лк 8:41-56",
+    "Лк 10:25-37",
+    "Лк 12:16-21",
+    "Лк 13:10-17",
+    "Лк 14:16-24",
+    "Лк 17:12-19",
+    "Лк 18:18-27",
+    "Лк 18:35-43",
+    "Лк 19:1-10"
+];
+
+/// Last Sunday read from Matthew.
+const LAST_MATTHEW_SUNDAY: usize = 17;
+
+/// Reading week of the Sunday of Zacchaeus, the last one before the Triodion.
+const LAST_READING_WEEK: usize = 32;
+
+/// Converts a Julian calendar date to a civil (Gregorian) date.
+fn from_julian(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    let a = (14 - i64::from(month)) / 12;
+    let y = i64::from(year) + 4800 - a;
+    let m = i64::from(month) + 12 * a - 3;
+    let jdn = i64::from(day) + (153 * m + 2) / 5 + 365 * y + y / 4 - 32083;
+    NaiveDate::from_num_days_from_ce_opt(i32::try_from(jdn - JDN_CE_OFFSET).ok()?)
+}
+
+/// Date of Pascha on the Julian Paschalion, in the civil calendar.
+#[must_use]
+pub fn pascha(year: i32) -> Option<NaiveDate> {
+    let leap = year.rem_euclid(4);
+    let week = year.rem_euclid(7);
+    let lunar = year.rem_euclid(19);
+    let full_moon = (19 * lunar + 15) % 30;
+    let to_sunday = (2 * leap + 4 * week - full_moon + 34) % 7;
+    let month = (full_moon + to_sunday + 114) / 31;
+    let day = (full_moon + to_sunday + 114) % 31 + 1;
+    from_julian(year, u32::try_from(month).ok()?, u32::try_from(day).ok()?)
+}
+
+/// Moveable feasts of the year with their civil dates.
+#[must_use]
+pub fn moveable_feasts(year: i32) -> Vec<(NaiveDate, &'static str)> {
+    let Some(pascha) = pascha(year) else {
+        return Vec::new();
+    };
+    [-70, -49, -7, 0, 39, 49]
+        .into_iter()
+        .filter_map(|offset| {
+            let (title, ..) = PASCHAL_CYCLE.iter().find(|(o, _)| *o == offset)?.1;
+            Some((add_days(pascha, offset)?, title))
+        })
+        .collect()
+}
+
+/// Civil date of a fixed feast in the given year.
+fn fixed_date(style: CalendarStyle, year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    match style {
+        CalendarStyle::Julian => from_julian(year, month, day),
+        CalendarStyle::RevisedJulian => NaiveDate::from_ymd_opt(year, month, day)
+    }
+}
+
+/// Shifts a date by a signed number of days.
+const fn add_days(date: NaiveDate, days: i64) -> Option<NaiveDate> {
+    let delta = Days::new(days.unsigned_abs());
+    if days < 0 {
+        date.checked_sub_days(delta)
+    } else {
+        date.checked_add_days(delta)
+    }
+}
+
+/// Parses a lectionary entry.
+fn lection((title, apostol, gospel): Entry) -> Lection {
+    let parse = |refs: &[&str]| refs.iter().filter_map(|r| Reference::parse(r)).collect();
+    Lection {
+        title:   title.to_string(),
+        apostol: parse(apostol),
+        gospel:  parse(gospel)
+    }
+}
+
+/// First Sunday strictly after a date.
+fn sunday_after(date: NaiveDate) -> Option<NaiveDate> {
+    date.checked_add_days(Days::new(u64::from(
+        7 - date.weekday().num_days_from_sunday()
+    )))
+}
+
+/// Whether a date falls on a church calendar day in either of the civil
+/// years it can land in.
+fn on_fixed_day(date: NaiveDate, style: CalendarStyle, month: u32, first: u32, last: u32) -> bool {
+    // Julian feasts late in the year fall into January of the next civil year
+    [date.year() - 1, date.year()].into_iter().any(|year| {
+        fixed_date(style, year, month, first)
+            .zip(fixed_date(style, year, month, last))
+            .is_some_and(|(first, last)| first <= date && date <= last)
+    })
+}
+
+/// Apostol and Gospel reading weeks for the week ending on `sunday`, the
+/// `week`-th after Pentecost.
+fn reading_weeks(
+    sunday: NaiveDate,
+    week: usize,
+    pentecost: NaiveDate,
+    next_pascha: NaiveDate,
+    style: CalendarStyle
+) -> Option<(usize, usize)> {
+    // Luke is read from the week after the Sunday after the Exaltation
+    let after_exaltation = sunday_after(fixed_date(style, pentecost.year(), 9, 14)?)?;
+    let gospel = if sunday > after_exaltation {
+        LAST_MATTHEW_SUNDAY + usize::try_from((sunday - after_exaltation).num_days() / 7).ok()?
+    } else {
+        week
+    };
+
+    let after_theophany = sunday_after(fixed_date(style, next_pascha.year(), 1, 6)?)?;
+    let zacchaeus = add_days(next_pascha, -77)?;
+    let left = usize::try_from((zacchaeus - sunday).num_days() / 7).ok()?;
+    if sunday <= after_theophany
+        && left > 0
+        && week <= LAST_READING_WEEK
+        && gospel <= LAST_READING_WEEK
+    {
+        return Some((week, gospel));
+    }
+    // Otherwise count back so that Zacchaeus is read on his Sunday
+    let shifted = LAST_READING_WEEK.saturating_sub(left).max(2);
+    Some((shifted, shifted))
+}
+
+/// Ordinary readings between the Sunday of All Saints and the Sunday of
+/// Zacchaeus.
+fn after_pentecost(date: NaiveDate, style: CalendarStyle) -> Option<Lection> {
+    let this_pascha = pascha(date.year())?;
+    let offset = (date - this_pascha).num_days();
+    if (-76..=56).contains(&offset) {
+        return None;
+    }
+    // Days in January and early February still count from last year's Pentecost
+    let (last_pascha, next_pascha) = if offset < 0 {
+        (pascha(date.year() - 1)?, this_pascha)
+    } else {
+        (this_pascha, pascha(date.year() + 1)?)
+    };
+    let pentecost = add_days(last_pascha, 49)?;
+
+    // Weekdays belong to the week of the following Sunday
+    let weekday = date.weekday();
+    let sunday = add_days(date, i64::from((7 - weekday.num_days_from_sunday()) % 7))?;
+    let week = usize::try_from((sunday - pentecost).num_days() / 7).ok()?;
+    let (apostol, gospel) = reading_weeks(sunday, week, pentecost, next_pascha, style)?;
+
+    if weekday == Weekday::Sun {
+        let title = if add_days(next_pascha, -77)? == date {
+            format!("Неделя {week}-я по Пятидесятнице, о Закхее")
+        } else {
+            format!("Неделя {week}-я по Пятидесятнице")
+        };
+        return Some(Lection {
+            title,
+            apostol: Reference::parse(SUNDAY_APOSTOL.get(apostol.checked_sub(2)?)?)
+                .into_iter()
+                .collect(),
+            gospel: Reference::parse(SUNDAY_GOSPEL.get(gospel.checked_sub(2)?)?)
+                .into_iter()
+                .collect()
+        });
+    }
+
+    let day = weekday.num_days_from_monday() as usize;
+    let readings = |n: usize| {
+        lectionary()
+            .weeks
+            .iter()
+            .find(|w| w.week == n)
+            .map(|w| &w.days[day])
+    };
+    Some(Lection {
+        title:   format!("{} {week}-й седмицы по Пятидесятнице", WEEKDAYS[day]),
+        apostol: parse_references(&readings(apostol)?.apostol),
+        gospel:  parse_references(&readings(gospel)?.gospel)
+    })
+}
+
+/// Readings appointed for a civil date.
+///
+/// Ordinary weekday readings give way to a great feast on the same day.
+///
+/// # Panics
+///
+/// Panics if the bundled `lectionary.json` is malformed.
+#[must_use]
+pub fn lections(date: NaiveDate, style: CalendarStyle) -> Vec<Lection> {
+    let mut result = Vec::new();
+
+    if let Some(pascha) = pascha(date.year()) {
+        let offset = (date - pascha).num_days();
+        if let Some((_, entry)) = PASCHAL_CYCLE.iter().find(|(o, _)| *o == offset) {
+            result.push(lection(*entry));
+        } else if let Some(day) = lectionary().paschal.iter().find(|d| d.day == offset) {
+            result.push(day.readings.lection(day.title.clone()));
+        }
+    }
+
+    let mut feast = false;
+    for (month, day, entry) in FIXED_FEASTS {
+        if on_fixed_day(date, style, month, day, day) {
+            result.push(lection(entry));
+            feast = true;
+        }
+    }
+
+    let sunday = date.weekday() == Weekday::Sun;
+    if sunday {
+        for (month, first, last, entry) in FIXED_SUNDAYS {
+            if on_fixed_day(date, style, month, first, last) {
+                result.push(lection(entry));
+            }
+        }
+    }
+
+    if (sunday || !feast)
+        && let Some(ordinary) = after_pentecost(date, style)
+    {
+        result.push(ordinary);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_parse(label: &str, references: &[impl AsRef<str>]) {
+        for reference in references {
+            let reference = reference.as_ref();
+            assert!(
+                Reference::parse(reference).is_some(),
+                "{label}: {reference}"
+            );
+        }
+    }
+
+    #[test]
+    fn bundled_references_parse() {
+        let lectionary = lectionary();
+        for day in &lectionary.paschal {
+            assert_parse(&day.title, &day.readings.apostol);
+            assert_parse(&day.title, &day.readings.gospel);
+        }
+        for week in &lectionary.weeks {
+            for (readings, weekday) in week.days.iter().zip(WEEKDAYS) {
+                let label = format!("{weekday} {}", week.week);
+                assert_parse(&label, &readings.apostol);
+                assert_parse(&label, &readings.gospel);
+            }
+        }
+    }
+
+    #[test]
+    fn table_references_parse() {
+        let entries = PASCHAL_CYCLE
+            .iter()
+            .map(|(_, entry)| entry)
+            .chain(FIXED_FEASTS.iter().map(|(_, _, entry)| entry))
+            .chain(FIXED_SUNDAYS.iter().map(|(_, _, _, entry)| entry));
+        for (title, apostol, gospel) in entries {
+            assert_parse(title, apostol);
+            assert_parse(title, gospel);
+        }
+        assert_parse("апостол недели", &SUNDAY_APOSTOL);
+        assert_parse("Евангелие недели", &SUNDAY_GOSPEL);
+    }
+}
@@ -0,0 +1,236 @@
+//! Bible commentary (Lopukhin's explanatory Bible) keyed by verse ranges.
+//!
+//! Loaded per book on demand. The offline pack is kept in Cache Storage,
+//! like downloaded audio, since all 66 books don't fit into the
+//! `LocalStorage` quota.
+
+use gloo_net::http::Request;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use crate::bible::book_code;
+
+#[cfg(debug_assertions)]
+const COMMENTARY_URL: &str = "/commentary/lopukhin";
+
+#[cfg(not(debug_assertions))]
+const COMMENTARY_URL: &str = "https://s3.twcstorage.ru/7f594bdf-revelation/commentary/lopukhin";
+
+/// Cache Storage bucket for the offline pack (kept by `sw.js`).
+const COMMENTARY_CACHE: &str = "revelation-commentary";
+const CACHE_VERSION_KEY: &str = "commentary_version";
+const OFFLINE_KEY: &str = "commentary_offline";
+const CURRENT_VERSION: &str = "1.0.0";
+
+/// Number of books in the Bible.
+const BOOKS_COUNT: i16 = 66;
+
+#[wasm_bindgen(inline_js = "
+export async function cacheUrl(name, url) {
+    const cache = await caches.open(name);
+    try {
+        await cache.add(url);
+    } catch (e) {
+        throw e.name === 'QuotaExceededError' ? 'quota' : String(e);
+    }
+}
+
+export async function cachedText(name, url) {
+    if (!('caches' in self)) {
+        return null;
+    }
+    const cache = await caches.open(name);
+    const hit = await cache.match(url);
+    return hit ? await hit.text() : null;
+}
+
+export async function deleteCache(name) {
+    await caches.delete(name);
+}
+")]
+extern "C" {
+    #[wasm_bindgen(catch)]
+    async fn cacheUrl(name: &str, url: &str) -> Result<(), JsValue>;
+    #[wasm_bindgen(catch)]
+    async fn cachedText(name: &str, url: &str) -> Result<JsValue, JsValue>;
+    #[wasm_bindgen(catch)]
+    async fn deleteCache(name: &str) -> Result<(), JsValue>;
+}
+
+/// Commentary on a verse range.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommentaryEntry {
+    /// First chapter.
+    pub chapter:     i16,
+    /// First verse.
+    pub verse:       i16,
+    /// Last chapter.
+    pub end_chapter: i16,
+    /// Last verse.
+    pub end_verse:   i16,
+    /// Commentary text, paragraphs separated by blank lines.
+    pub text:        String
+}
+
+impl CommentaryEntry {
+    /// Checks whether the range touches a chapter.
+    #[must_use]
+    pub const fn covers_chapter(&self, chapter: i16) -> bool {
+        self.chapter <= chapter && chapter <= self.end_chapter
+    }
+
+    /// Checks whether the range includes a verse.
+    #[must_use]
+    pub fn contains(&self, chapter: i16, verse: i16) -> bool {
+        (self.chapter, self.verse) <= (chapter, verse)
+            && (chapter, verse) <= (self.end_chapter, self.end_verse)
+    }
+
+    /// Range label such as `ст. 1-5` or `1:20-2:3`.
+    #[must_use]
+    pub fn label(&self) -> String {
+        if self.chapter != self.end_chapter {
+            format!(
+                "{}:{}-{}:{}",
+                self.chapter, self.verse, self.end_chapter, self.end_verse
+            )
+        } else if self.verse == self.end_verse {
+            format!("ст. {}", self.verse)
+        } else {
+            format!("ст. {}-{}", self.verse, self.end_verse)
+        }
+    }
+}
+
+/// Commentary on one book.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookCommentary {
+    /// Entries in canonical order.
+    pub entries: Vec<CommentaryEntry>
+}
+
+impl BookCommentary {
+    /// Entries touching a chapter.
+    #[must_use]
+    pub fn for_chapter(&self, chapter: i16) -> Vec<CommentaryEntry> {
+        self.entries
+            .iter()
+            .filter(|e| e.covers_chapter(chapter))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Commentary data provider with caching.
+pub struct CommentaryProvider;
+
+impl CommentaryProvider {
+    /// Checks whether the offline pack is downloaded.
+    #[must_use]
+    pub fn is_downloaded() -> bool {
+        LocalStorage::get::<bool>(OFFLINE_KEY).unwrap_or(false) && Self::is_cache_valid()
+    }
+
+    /// Checks if cache is valid.
+    fn is_cache_valid() -> bool {
+        LocalStorage::get::<String>(CACHE_VERSION_KEY).is_ok_and(|v| v == CURRENT_VERSION)
+    }
+
+    /// Download URL of a book.
+    fn book_url(book_id: i16) -> Option<String> {
+        book_code(book_id).map(|code| format!("{COMMENTARY_URL}/{code}.json"))
+    }
+
+    /// Loads a book from the offline pack.
+    async fn load_from_cache(book_id: i16) -> Option<BookCommentary> {
+        if !Self::is_cache_valid() {
+            return None;
+        }
+        let text = cachedText(COMMENTARY_CACHE, &Self::book_url(book_id)?)
+            .await
+            .ok()?
+            .as_string()?;
+        serde_json::from_str(&text).ok()
+    }
+
+    /// Saves a book into the offline pack.
+    async fn save_to_cache(book_id: i16) -> Result<(), String> {
+        let url = Self::book_url(book_id).ok_or("Unknown book")?;
+        cacheUrl(COMMENTARY_CACHE, &url).await.map_err(|e| {
+            if e.as_string().as_deref() == Some("quota") {
+                "Недостаточно места на устройстве".to_string()
+            } else {
+                format!("Download error: {e:?}")
+            }
+        })
+    }
+
+    /// Fetches a book from the server.
+    async fn fetch_book(book_id: i16) -> Result<BookCommentary, String> {
+        let url = Self::book_url(book_id).ok_or("Unknown book")?;
+        let response = Request::get(&url)
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {e}"))?;
+
+        if !response.ok() {
+            return Err(format!("HTTP error: {}", response.status()));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| format!("Parse error: {e}"))
+    }
+
+    /// Loads commentary on a book from the offline pack or the server.
+    ///
+    /// # Errors
+    ///
+    /// Returns error string if the book is not cached and fetch fails.
+    pub async fn load(book_id: i16) -> Result<BookCommentary, String> {
+        if let Some(commentary) = Self::load_from_cache(book_id).await {
+            return Ok(commentary);
+        }
+        Self::fetch_book(book_id).await
+    }
+
+    /// Downloads the whole commentary for offline use.
+    ///
+    /// Calls `on_progress` with the number of books saved so far.
+    ///
+    /// # Errors
+    ///
+    /// Returns error string if a book cannot be fetched or stored, with a
+    /// readable message when the device runs out of space; books saved
+    /// before the error are kept.
+    pub async fn download(on_progress: impl Fn(i16)) -> Result<(), String> {
+        // Books saved for an older version must not pass as current
+        if !Self::is_cache_valid() {
+            Self::remove().await?;
+        }
+        LocalStorage::set(CACHE_VERSION_KEY, CURRENT_VERSION)
+            .map_err(|e| format!("Storage error: {e}"))?;
+        for book_id in 1..=BOOKS_COUNT {
+            if Self::load_from_cache(book_id).await.is_none() {
+                Self::save_to_cache(book_id).await?;
+            }
+            on_progress(book_id);
+        }
+        LocalStorage::set(OFFLINE_KEY, true).map_err(|e| format!("Storage error: {e}"))
+    }
+
+    /// Deletes the offline pack.
+    ///
+    /// # Errors
+    ///
+    /// Returns error string if the cache cannot be deleted.
+    pub async fn remove() -> Result<(), String> {
+        deleteCache(COMMENTARY_CACHE)
+            .await
+            .map_err(|e| format!("Cache error: {e:?}"))?;
+        LocalStorage::delete(OFFLINE_KEY);
+        Ok(())
+    }
+}
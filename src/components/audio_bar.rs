@@ -0,0 +1,321 @@
+//! Audio Bible player - recorded chapters with verse sync and lock-screen
+//! controls.
+
+use std::time::Duration;
+
+use leptos::{html, prelude::*};
+use wasm_bindgen_futures::spawn_local;
+
+use crate::{
+    audio::{self, AudioDownloads, ListenSource, MediaAction, VerseTiming},
+    haptic,
+    reference::Reference,
+    speech,
+    state::AppState
+};
+
+stylance::import_crate_style!(styles, "src/styles/listen.module.css");
+
+/// Sleep timer setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum SleepTimer {
+    #[default]
+    Off,
+    Minutes(u64),
+    EndOfChapter
+}
+
+impl SleepTimer {
+    /// Next option when the timer button is tapped.
+    const fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Minutes(15),
+            Self::Minutes(15) => Self::Minutes(30),
+            Self::Minutes(30) => Self::Minutes(60),
+            Self::Minutes(_) => Self::EndOfChapter,
+            Self::EndOfChapter => Self::Off
+        }
+    }
+
+    fn label(self) -> String {
+        match self {
+            Self::Off => "☾".to_string(),
+            Self::Minutes(m) => format!("☾ {m}"),
+            Self::EndOfChapter => "☾ гл.".to_string()
+        }
+    }
+}
+
+/// Audio element plus the timings of the loaded chapter.
+#[derive(Clone, Copy)]
+struct Player {
+    audio_ref: NodeRef<html::Audio>,
+    timings:   LocalResource<Option<Vec<VerseTiming>>>
+}
+
+impl Player {
+    /// Verse at the current playback position.
+    fn current_verse(self) -> Option<i16> {
+        let el = self.audio_ref.get_untracked()?;
+        let timings = self.timings.get_untracked().flatten()?;
+        audio::verse_at(&timings, el.current_time())
+    }
+
+    /// Updates the highlighted verse from the playback position.
+    fn sync(self, spoken: RwSignal<Option<i16>>) {
+        let verse = self.current_verse();
+        if spoken.get_untracked() != verse {
+            spoken.set(verse);
+        }
+    }
+
+    /// Seeks `delta` verses from the current one.
+    fn skip(self, delta: i16) {
+        let Some(el) = self.audio_ref.get_untracked() else {
+            return;
+        };
+        let Some(timings) = self.timings.get_untracked().flatten() else {
+            return;
+        };
+        let current = audio::verse_at(&timings, el.current_time()).unwrap_or(1);
+        if let Some(start) = audio::verse_start(&timings, (current + delta).max(1)) {
+            el.set_current_time(start);
+        }
+    }
+
+    /// Plays or pauses.
+    fn toggle(self) {
+        let Some(el) = self.audio_ref.get_untracked() else {
+            return;
+        };
+        if el.paused() {
+            let _ = el.play();
+        } else {
+            let _ = el.pause();
+        }
+    }
+
+    /// Starts playback.
+    fn play(self) {
+        if let Some(el) = self.audio_ref.get_untracked() {
+            let _ = el.play();
+        }
+    }
+
+    /// Pauses playback.
+    fn pause(self) {
+        if let Some(el) = self.audio_ref.get_untracked() {
+            let _ = el.pause();
+        }
+    }
+}
+
+/// Playback bar for recorded audio.
+#[must_use]
+#[component]
+pub fn AudioBar(
+    /// Verse being read, highlighted by the reader.
+    spoken: RwSignal<Option<i16>>,
+    /// Moves to the next chapter, returns `false` at the end of the Bible.
+    advance: impl Fn() -> bool + Send + Sync + 'static,
+    /// Closes listen mode.
+    on_close: impl Fn() + Copy + Send + Sync + 'static
+) -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let audio_ref: NodeRef<html::Audio> = NodeRef::new();
+    let playing = RwSignal::new(false);
+    let unavailable = RwSignal::new(false);
+    let sleep = RwSignal::new(SleepTimer::Off);
+    // Keeps playing after moving to the next chapter
+    let autoplay = StoredValue::new(false);
+
+    let chapter = Memo::new(move |_| (state.current_book.get(), state.current_chapter.get()));
+
+    let timings = LocalResource::new(move || {
+        let (book, chapter) = chapter.get();
+        async move { audio::fetch_timings(book, chapter).await.ok() }
+    });
+
+    let src = move || {
+        let (book, chapter) = chapter.get();
+        audio::audio_url(book, chapter).unwrap_or_default()
+    };
+
+    let player = Player {
+        audio_ref,
+        timings
+    };
+
+    Effect::new(move |_| {
+        let (book, number) = chapter.get();
+        spoken.set(None);
+        unavailable.set(false);
+        audio::register_media_session(book, number, move |action| match action {
+            MediaAction::Play => player.play(),
+            MediaAction::Pause => player.pause(),
+            MediaAction::Previous => player.skip(-1),
+            MediaAction::Next => player.skip(1)
+        });
+    });
+
+    on_cleanup(move || {
+        audio::clear_media_session();
+        spoken.set(None);
+    });
+
+    view! {
+        <div class=styles::bar>
+            <audio
+                node_ref=audio_ref
+                src=src
+                preload="metadata"
+                on:loadedmetadata=move |_| {
+                    if autoplay.get_value() {
+                        autoplay.set_value(false);
+                        player.toggle();
+                    }
+                }
+                on:play=move |_| { playing.set(true); audio::set_media_playing(true); }
+                on:pause=move |_| { playing.set(false); audio::set_media_playing(false); }
+                on:timeupdate=move |_| player.sync(spoken)
+                on:ended=move |_| {
+                    if sleep.get_untracked() == SleepTimer::EndOfChapter {
+                        sleep.set(SleepTimer::Off);
+                    } else if advance() {
+                        autoplay.set_value(true);
+                    }
+                }
+                on:error=move |_| unavailable.set(true)
+            />
+            <span class=styles::label>
+                {move || if unavailable.get() {
+                    "Нет записи".to_string()
+                } else {
+                    let (book, number) = chapter.get();
+                    Reference::at(book, number, spoken.get()).to_string()
+                }}
+            </span>
+            <button
+                class=styles::control
+                aria-label="Предыдущий стих"
+                on:click=move |_| { haptic::tap(); player.skip(-1); }
+            >"⏮"</button>
+            <button
+                class=format!("{} {}", styles::control, styles::play)
+                aria-label=move || if playing.get() { "Пауза" } else { "Слушать" }
+                disabled=move || unavailable.get()
+                on:click=move |_| { haptic::tap(); player.toggle(); }
+            >{move || if playing.get() { "⏸" } else { "▶" }}</button>
+            <button
+                class=styles::control
+                aria-label="Следующий стих"
+                on:click=move |_| { haptic::tap(); player.skip(1); }
+            >"⏭"</button>
+            <SleepButton sleep=sleep audio_ref=audio_ref/>
+            <DownloadButton chapter=chapter unavailable=unavailable/>
+            <Show when=speech::is_supported>
+                <button
+                    class=styles::rate
+                    aria-label="Синтез речи"
+                    on:click=move |_| state.listen_source.set(ListenSource::Speech)
+                >"Голос"</button>
+            </Show>
+            <button
+                class=styles::close
+                aria-label="Закрыть"
+                on:click=move |_| on_close()
+            >"✕"</button>
+        </div>
+    }
+}
+
+/// Sleep timer toggle: pauses playback after a delay or at the chapter end.
+#[component]
+fn SleepButton(sleep: RwSignal<SleepTimer>, audio_ref: NodeRef<html::Audio>) -> impl IntoView {
+    let handle = StoredValue::new(None::<TimeoutHandle>);
+
+    let cycle_sleep = move |_| {
+        haptic::tap();
+        if let Some(handle) = handle.get_value() {
+            handle.clear();
+        }
+        let next = sleep.get_untracked().next();
+        sleep.set(next);
+        handle.set_value(match next {
+            SleepTimer::Minutes(minutes) => set_timeout_with_handle(
+                move || {
+                    if let Some(el) = audio_ref.get_untracked() {
+                        let _ = el.pause();
+                    }
+                    sleep.set(SleepTimer::Off);
+                },
+                Duration::from_secs(minutes * 60)
+            )
+            .ok(),
+            SleepTimer::Off | SleepTimer::EndOfChapter => None
+        });
+    };
+
+    on_cleanup(move || {
+        if let Some(handle) = handle.get_value() {
+            handle.clear();
+        }
+    });
+
+    view! {
+        <button
+            class=move || if sleep.get() == SleepTimer::Off {
+                styles::rate.to_string()
+            } else {
+                format!("{} {}", styles::rate, styles::rateActive)
+            }
+            aria-label="Таймер сна"
+            on:click=cycle_sleep
+        >{move || sleep.get().label()}</button>
+    }
+}
+
+/// Saves the current chapter for offline listening.
+#[component]
+fn DownloadButton(chapter: Memo<(i16, i16)>, unavailable: RwSignal<bool>) -> impl IntoView {
+    let downloaded = RwSignal::new(false);
+    let downloading = RwSignal::new(false);
+
+    Effect::new(move |_| {
+        let (book, number) = chapter.get();
+        downloaded.set(AudioDownloads::contains(book, number));
+    });
+
+    let on_download = move |_| {
+        if downloaded.get_untracked() || downloading.get_untracked() {
+            return;
+        }
+        haptic::tap();
+        let (book, number) = chapter.get_untracked();
+        downloading.set(true);
+        spawn_local(async move {
+            match AudioDownloads::download(book, number).await {
+                Ok(()) => {
+                    haptic::success();
+                    downloaded.set(true);
+                }
+                Err(e) => {
+                    haptic::error();
+                    web_sys::console::error_1(&e.into());
+                }
+            }
+            downloading.set(false);
+        });
+    };
+
+    view! {
+        <button
+            class=styles::control
+            aria-label="Скачать главу"
+            disabled=move || unavailable.get()
+            on:click=on_download
+        >
+            {move || if downloaded.get() { "✓" } else if downloading.get() { "…" } else { "⤓" }}
+        </button>
+    }
+}
@@ -0,0 +1,245 @@
+//! Command palette - jump to passages, songs and pages from the keyboard.
+
+use leptos::{ev, html, prelude::*};
+use leptos_router::{NavigateOptions, hooks::use_navigate};
+use wasm_bindgen_futures::spawn_local;
+
+use crate::{
+    api,
+    bible::book_name,
+    reference::{Reference, match_books},
+    state::AppState
+};
+
+stylance::import_crate_style!(styles, "src/styles/palette.module.css");
+
+/// Pages reachable from the palette.
+const PAGES: [(&str, &str); 17] = [
+    ("Библия", "/bible"),
+    ("Поиск", "/search"),
+    ("Чтение на сегодня", "/today"),
+    ("Планы чтения", "/plans"),
+    ("Статистика", "/stats"),
+    ("Заучивание", "/memorize"),
+    ("Словарь", "/dictionary"),
+    ("Темы", "/topics"),
+    ("Карты", "/maps"),
+    ("Гармония Евангелий", "/harmony"),
+    ("Проектор", "/present"),
+    ("Печать", "/print"),
+    ("Песни", "/songs"),
+    ("Лента", "/feed"),
+    ("Церкви", "/churches"),
+    ("Профиль", "/profile"),
+    ("Настройки", "/settings")
+];
+
+/// Maximum number of suggestions shown.
+const MAX_ITEMS: usize = 8;
+
+/// Palette action.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Command {
+    /// Open a passage in the reader.
+    Passage(Reference),
+    /// Open a song by its number.
+    Song(u32),
+    /// Open a page.
+    Page(&'static str, &'static str)
+}
+
+impl Command {
+    fn label(&self) -> String {
+        match self {
+            Self::Passage(r) => {
+                let name = book_name(r.book_id).unwrap_or_default();
+                let location = r.to_string();
+                let location = location.split_once(' ').map_or("", |(_, loc)| loc);
+                format!("{name} {location}")
+            }
+            Self::Song(number) => format!("Песня №{number}"),
+            Self::Page(label, _) => (*label).to_string()
+        }
+    }
+
+    const fn kind(&self) -> &'static str {
+        match self {
+            Self::Passage(_) => "Библия",
+            Self::Song(_) => "Песни",
+            Self::Page(..) => "Раздел"
+        }
+    }
+}
+
+/// Builds suggestions for the query.
+fn suggest(query: &str) -> Vec<Command> {
+    let query = query.trim();
+    if query.is_empty() {
+        return PAGES
+            .iter()
+            .map(|(label, path)| Command::Page(label, path))
+            .collect();
+    }
+
+    let mut items = Vec::new();
+
+    if let Some(reference) = Reference::parse(query) {
+        items.push(Command::Passage(reference));
+    } else {
+        items.extend(
+            match_books(query)
+                .into_iter()
+                .map(|id| Command::Passage(Reference::chapter(id, 1)))
+        );
+    }
+
+    let number = query
+        .trim_start_matches(['#', '№'])
+        .trim_start_matches("песня")
+        .trim();
+    if let Ok(n) = number.parse::<u32>() {
+        items.push(Command::Song(n));
+    }
+
+    let lower = query.to_lowercase();
+    items.extend(
+        PAGES
+            .iter()
+            .filter(|(label, _)| label.to_lowercase().contains(&lower))
+            .map(|(label, path)| Command::Page(label, path))
+    );
+
+    items.truncate(MAX_ITEMS);
+    items
+}
+
+/// Executes a palette command.
+fn run_command(
+    command: Command,
+    state: &AppState,
+    navigate: impl Fn(&str, NavigateOptions) + Clone + 'static
+) {
+    match command {
+        Command::Passage(r) => {
+            state.current_book.set(r.book_id);
+            state.current_chapter.set(r.chapter);
+            state.focus_verse.set(r.verse);
+            navigate(
+                &format!("/bible/{}/{}", r.book_id, r.chapter),
+                NavigateOptions::default()
+            );
+        }
+        Command::Song(number) => {
+            spawn_local(async move {
+                let number = number.to_string();
+                let song = api::search_songs(&number).await.ok().and_then(|results| {
+                    results
+                        .into_iter()
+                        .find(|r| r.song.number.is_some_and(|n| n.to_string() == number))
+                });
+                let path = song.map_or_else(
+                    || "/songs/search".to_string(),
+                    |r| format!("/songs/{}", r.song.id)
+                );
+                navigate(&path, NavigateOptions::default());
+            });
+        }
+        Command::Page(_, path) => navigate(path, NavigateOptions::default())
+    }
+}
+
+/// Ctrl+K command palette.
+#[component]
+pub fn CommandPalette(open: RwSignal<bool>) -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let navigate = use_navigate();
+    let query = RwSignal::new(String::new());
+    let selected = RwSignal::new(0_usize);
+    let input_ref: NodeRef<html::Input> = NodeRef::new();
+
+    let items = Memo::new(move |_| suggest(&query.get()));
+
+    Effect::new(move |_| {
+        if let Some(input) = input_ref.get() {
+            let _ = input.focus();
+        }
+    });
+
+    let run = move |command: Command| {
+        open.set(false);
+        run_command(command, &state, navigate.clone());
+    };
+
+    let on_keydown = {
+        let run = run.clone();
+        move |ev: ev::KeyboardEvent| match ev.key().as_str() {
+            "Escape" => open.set(false),
+            "ArrowDown" => {
+                ev.prevent_default();
+                let count = items.with_untracked(Vec::len);
+                selected.update(|i| *i = (*i + 1).min(count.saturating_sub(1)));
+            }
+            "ArrowUp" => {
+                ev.prevent_default();
+                selected.update(|i| *i = i.saturating_sub(1));
+            }
+            "Enter" => {
+                let command =
+                    items.with_untracked(|items| items.get(selected.get_untracked()).copied());
+                if let Some(command) = command {
+                    run(command);
+                }
+            }
+            _ => {}
+        }
+    };
+
+    view! {
+        <div class=styles::overlay on:click=move |_| open.set(false)>
+            <div class=styles::palette on:click=|ev| ev.stop_propagation()>
+                <input
+                    node_ref=input_ref
+                    type="text"
+                    class=styles::input
+                    placeholder="Ин 3:16, песня 25, настройки..."
+                    prop:value=query
+                    on:input=move |ev| {
+                        query.set(event_target_value(&ev));
+                        selected.set(0);
+                    }
+                    on:keydown=on_keydown
+                />
+                <ul class=styles::list>
+                    {move || {
+                        let run = run.clone();
+                        items.get().into_iter().enumerate().map(|(idx, command)| {
+                            let run = run.clone();
+                            let label = command.label();
+                            let kind = command.kind();
+                            view! {
+                                <li
+                                    class=move || if selected.get() == idx {
+                                        format!("{} {}", styles::item, styles::itemActive)
+                                    } else {
+                                        styles::item.to_string()
+                                    }
+                                    on:mouseenter=move |_| selected.set(idx)
+                                    on:click=move |_| run(command)
+                                >
+                                    <span class=styles::itemLabel>{label}</span>
+                                    <span class=styles::itemKind>{kind}</span>
+                                </li>
+                            }
+                        }).collect::<Vec<_>>()
+                    }}
+                </ul>
+                <div class=styles::hints>
+                    <span><kbd>"←"</kbd><kbd>"→"</kbd>" главы"</span>
+                    <span><kbd>"B"</kbd>" книги"</span>
+                    <span><kbd>"/"</kbd>" поиск"</span>
+                    <span><kbd>"T"</kbd>" тема"</span>
+                </div>
+            </div>
+        </div>
+    }
+}
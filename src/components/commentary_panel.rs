@@ -0,0 +1,74 @@
+//! Commentary panel following the chapter open in the reader.
+
+use leptos::prelude::*;
+
+use super::Loading;
+use crate::commentary::{CommentaryEntry, CommentaryProvider};
+
+stylance::import_crate_style!(styles, "src/styles/commentary.module.css");
+
+/// Commentary on the current chapter.
+///
+/// A side pane on desktop and a bottom sheet on mobile.
+#[must_use]
+#[component]
+pub fn CommentaryPanel(
+    /// Book open in the reader.
+    #[prop(into)]
+    book: Signal<i16>,
+    /// Chapter open in the reader.
+    #[prop(into)]
+    chapter: Signal<i16>,
+    /// Closes the panel.
+    on_close: impl Fn() + Copy + Send + Sync + 'static
+) -> impl IntoView {
+    let commentary = LocalResource::new(move || {
+        let book = book.get();
+        async move { CommentaryProvider::load(book).await }
+    });
+
+    let entries = move || {
+        commentary
+            .get()
+            .map(|result| result.map(|c| c.for_chapter(chapter.get())))
+    };
+
+    view! {
+        <aside class=styles::panel>
+            <div class=styles::head>
+                <h2 class=styles::title>"Толковая Библия Лопухина"</h2>
+                <button class=styles::close aria-label="Закрыть" on:click=move |_| on_close()>"✕"</button>
+            </div>
+            <div class=styles::body>
+                <Suspense fallback=|| view! { <Loading/> }>
+                    {move || entries().map(|entries| match entries {
+                        Ok(entries) if entries.is_empty() => view! {
+                            <p class=styles::empty>"К этой главе толкования нет"</p>
+                        }.into_any(),
+                        Ok(entries) => entries.into_iter().map(|entry| view! {
+                            <Entry entry=entry/>
+                        }).collect_view().into_any(),
+                        Err(_) => view! {
+                            <p class=styles::empty>
+                                "Толкование недоступно без сети. Скачайте его в настройках, чтобы читать офлайн."
+                            </p>
+                        }.into_any()
+                    })}
+                </Suspense>
+            </div>
+        </aside>
+    }
+}
+
+/// Commentary on one verse range.
+#[component]
+fn Entry(entry: CommentaryEntry) -> impl IntoView {
+    view! {
+        <section class=styles::entry>
+            <span class=styles::range>{entry.label()}</span>
+            {entry.text.split("\n\n").map(|paragraph| view! {
+                <p class=styles::paragraph>{paragraph.trim().to_string()}</p>
+            }).collect_view()}
+        </section>
+    }
+}
@@ -0,0 +1,253 @@
+//! Find-in-chapter - search bar, match highlighting and dictionary name
+//! links for the reader.
+
+use std::ops::Range;
+
+use leptos::{html, prelude::*};
+use leptos_router::{NavigateOptions, hooks::use_navigate};
+use revelation_bible::Verse;
+
+use crate::{
+    dictionary::dictionary,
+    search::{find_matches, word_ranges},
+    state::AppState
+};
+
+stylance::import_crate_style!(styles, "src/styles/find.module.css");
+
+/// Find-in-chapter state shared by the bar and highlighted verses.
+#[derive(Clone, Copy)]
+pub struct FindState {
+    /// Text being searched.
+    pub query:   RwSignal<String>,
+    /// Matches in reading order as `(verse, byte range)`.
+    pub matches: Memo<Vec<(i16, Range<usize>)>>,
+    /// Index of the current match.
+    pub current: RwSignal<usize>
+}
+
+impl FindState {
+    /// Creates find state over the verses of a chapter.
+    #[must_use]
+    pub fn new(verses: Signal<Vec<Verse>>) -> Self {
+        let query = RwSignal::new(String::new());
+        let matches = Memo::new(move |_| {
+            let query = query.get();
+            verses.with(|verses| {
+                verses
+                    .iter()
+                    .flat_map(|v| {
+                        find_matches(&v.text, &query)
+                            .into_iter()
+                            .map(move |range| (v.verse, range))
+                    })
+                    .collect()
+            })
+        });
+        Self {
+            query,
+            matches,
+            current: RwSignal::new(0)
+        }
+    }
+
+    /// Moves to the next match, or the previous one when `back` is set.
+    pub fn step(self, back: bool) {
+        let count = self.matches.with_untracked(Vec::len);
+        if count == 0 {
+            return;
+        }
+        self.current.update(|current| {
+            *current = if back {
+                (*current + count - 1) % count
+            } else {
+                (*current + 1) % count
+            };
+        });
+    }
+}
+
+/// Search bar for the open chapter with a match counter.
+#[must_use]
+#[component]
+pub fn FindBar(
+    /// Find state provided to the verses.
+    find: FindState,
+    /// Closes the bar.
+    on_close: impl Fn() + Copy + Send + Sync + 'static
+) -> impl IntoView {
+    let input_ref: NodeRef<html::Input> = NodeRef::new();
+
+    Effect::new(move |_| {
+        if let Some(input) = input_ref.get() {
+            let _ = input.focus();
+        }
+    });
+
+    // Keep the current match in view
+    Effect::new(move |_| {
+        let current = find.current.get();
+        if find.matches.with(Vec::is_empty) {
+            return;
+        }
+        if let Some(el) = document().get_element_by_id(&format!("m{current}")) {
+            let options = web_sys::ScrollIntoViewOptions::new();
+            options.set_block(web_sys::ScrollLogicalPosition::Center);
+            el.scroll_into_view_with_scroll_into_view_options(&options);
+        }
+    });
+
+    let counter = move || {
+        let count = find.matches.with(Vec::len);
+        if find.query.with(|q| q.trim().is_empty()) {
+            String::new()
+        } else if count == 0 {
+            "Нет совпадений".to_string()
+        } else {
+            format!("{} из {count}", find.current.get().min(count - 1) + 1)
+        }
+    };
+
+    let close = move || {
+        find.query.set(String::new());
+        on_close();
+    };
+
+    view! {
+        <div class=styles::bar>
+            <input
+                node_ref=input_ref
+                type="search"
+                class=styles::input
+                placeholder="Найти в главе"
+                prop:value=find.query
+                on:input=move |ev| {
+                    find.query.set(event_target_value(&ev));
+                    find.current.set(0);
+                }
+                on:keydown=move |ev| match ev.key().as_str() {
+                    "Enter" => {
+                        ev.prevent_default();
+                        find.step(ev.shift_key());
+                    }
+                    "Escape" => close(),
+                    _ => {}
+                }
+            />
+            <span class=styles::counter>{counter}</span>
+            <button
+                class=styles::action
+                aria-label="Предыдущее совпадение"
+                disabled=move || find.matches.with(Vec::is_empty)
+                on:click=move |_| find.step(true)
+            >"↑"</button>
+            <button
+                class=styles::action
+                aria-label="Следующее совпадение"
+                disabled=move || find.matches.with(Vec::is_empty)
+                on:click=move |_| find.step(false)
+            >"↓"</button>
+            <button class=styles::action aria-label="Закрыть" on:click=move |_| close()>"✕"</button>
+        </div>
+    }
+}
+
+/// Part of a verse rendered apart from plain text.
+enum Span {
+    /// Find match with its index in the whole chapter.
+    Match(usize),
+    /// Dictionary name with the entry id.
+    Name(&'static str)
+}
+
+/// Verse text with find-in-chapter matches highlighted and, when enabled,
+/// dictionary names linked.
+///
+/// Renders plain text when the reader has no find state.
+#[must_use]
+#[component]
+pub fn HighlightedText(
+    /// Verse number.
+    verse: i16,
+    /// Verse text.
+    text: String
+) -> impl IntoView {
+    let find = use_context::<FindState>();
+    let link_names = use_context::<AppState>().map(|s| s.link_names);
+    let navigate = use_navigate();
+
+    move || {
+        let mut spans: Vec<(Range<usize>, Span)> = find
+            .map(|find| {
+                find.matches.with(|matches| {
+                    matches
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, (v, _))| *v == verse)
+                        .map(|(idx, (_, range))| (range.clone(), Span::Match(idx)))
+                        .collect()
+                })
+            })
+            .unwrap_or_default();
+
+        // Names overlapping a find match are left unlinked
+        if link_names.is_some_and(|l| l.get()) {
+            let dictionary = dictionary();
+            let names: Vec<_> = word_ranges(&text)
+                .filter_map(|range| {
+                    let entry = dictionary.lookup(&text[range.clone()])?;
+                    let free = spans
+                        .iter()
+                        .all(|(r, _)| r.end <= range.start || range.end <= r.start);
+                    free.then_some((range, Span::Name(entry.id.as_str())))
+                })
+                .collect();
+            spans.extend(names);
+            spans.sort_by_key(|(range, _)| range.start);
+        }
+
+        let mut parts = Vec::with_capacity(spans.len() * 2 + 1);
+        let mut pos = 0;
+        for (range, span) in spans {
+            parts.push(text[pos..range.start].to_string().into_any());
+            let content = text[range.clone()].to_string();
+            parts.push(match span {
+                Span::Match(idx) => view! {
+                    <mark
+                        id=format!("m{idx}")
+                        class=move || if find.is_some_and(|f| f.current.get() == idx) {
+                            format!("{} {}", styles::mark, styles::markCurrent)
+                        } else {
+                            styles::mark.to_string()
+                        }
+                    >
+                        {content}
+                    </mark>
+                }
+                .into_any(),
+                Span::Name(id) => {
+                    let navigate = navigate.clone();
+                    let href = format!("/dictionary/{id}");
+                    view! {
+                        <a
+                            class=styles::name
+                            href=href.clone()
+                            on:click=move |ev| {
+                                // Keep the tap from selecting the verse
+                                ev.prevent_default();
+                                ev.stop_propagation();
+                                navigate(&href, NavigateOptions::default());
+                            }
+                        >
+                            {content}
+                        </a>
+                    }
+                    .into_any()
+                }
+            });
+            pos = range.end;
+        }
+        parts.push(text[pos..].to_string().into_any());
+        parts
+    }
+}
@@ -0,0 +1,129 @@
+//! Parallel accounts of a Gospel event, side by side.
+
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use super::{Loading, VerseList};
+use crate::{
+    harmony::{Gospel, HarmonyEvent, harmony},
+    state::AppState
+};
+
+stylance::import_crate_style!(styles, "src/styles/harmony.module.css");
+
+/// Columns with the text of each Gospel telling the event.
+#[must_use]
+#[component]
+pub fn GospelAccounts(
+    /// Event to show.
+    event: &'static HarmonyEvent,
+    /// Gospel left out, usually the one being read.
+    #[prop(default = None)]
+    exclude: Option<Gospel>,
+    /// Opens a passage as `(book, chapter, verse)`; plain links when unset.
+    #[prop(optional)]
+    on_jump: Option<Callback<(i16, i16, i16)>>
+) -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let accounts: Vec<_> = event
+        .accounts()
+        .into_iter()
+        .filter(|(g, _)| Some(*g) != exclude)
+        .collect();
+
+    if accounts.is_empty() {
+        return view! { <p class=styles::empty>"Другие Евангелия об этом не повествуют"</p> }
+            .into_any();
+    }
+
+    view! {
+        <div class=styles::columns>
+            {accounts.into_iter().map(|(gospel, reference)| {
+                let verse = reference.verse.unwrap_or(1);
+                let title = format!("{} · {reference}", gospel.label());
+                let link = match on_jump {
+                    Some(on_jump) => view! {
+                        <button
+                            class=styles::accountTitle
+                            on:click=move |_| on_jump.run((reference.book_id, reference.chapter, verse))
+                        >{title}</button>
+                    }.into_any(),
+                    None => view! {
+                        <A
+                            href=format!("/bible/{}/{}?verse={verse}", reference.book_id, reference.chapter)
+                            attr:class=styles::accountTitle
+                        >{title}</A>
+                    }.into_any()
+                };
+                view! {
+                    <section class=styles::account>
+                        {link}
+                        {move || state.bible.with(|bible| bible.as_ref().map_or_else(
+                            || view! { <Loading/> }.into_any(),
+                            |bible| view! { <VerseList verses=bible.get_passage(&reference)/> }.into_any()
+                        ))}
+                    </section>
+                }
+            }).collect_view()}
+        </div>
+    }
+    .into_any()
+}
+
+/// Bottom sheet with the parallel accounts of the events in a chapter.
+///
+/// Opens on the event containing `verse`, or on the first one.
+#[must_use]
+#[component]
+pub fn HarmonySheet(
+    /// Gospel book open in the reader.
+    book: i16,
+    /// Chapter open in the reader.
+    chapter: i16,
+    /// Selected verse.
+    verse: Option<i16>,
+    /// Closes the sheet.
+    on_close: impl Fn() + Copy + Send + Sync + 'static,
+    /// Opens a passage as `(book, chapter, verse)`.
+    on_jump: Callback<(i16, i16, i16)>
+) -> impl IntoView {
+    let events = harmony().events_in(book, chapter);
+    let initial = verse
+        .and_then(|v| events.iter().position(|e| e.contains(book, chapter, v)))
+        .unwrap_or(0);
+    let open = RwSignal::new(Some(initial));
+    let gospel = Gospel::from_book(book);
+
+    view! {
+        <div class=styles::overlay on:click=move |_| on_close()>
+            <div class=styles::sheet on:click=|ev| ev.stop_propagation()>
+                <div class=styles::head>
+                    <h2 class=styles::title>"Параллельные места"</h2>
+                    <button class=styles::close aria-label="Закрыть" on:click=move |_| on_close()>
+                        "✕"
+                    </button>
+                </div>
+                {events.into_iter().enumerate().map(|(idx, event)| {
+                    let own = gospel.and_then(|g| event.passage(g)).map(|r| r.to_string());
+                    view! {
+                        <div class=styles::event>
+                            <button
+                                class=styles::eventHead
+                                aria-expanded=move || (open.get() == Some(idx)).to_string()
+                                on:click=move |_| open.update(|o| {
+                                    *o = if *o == Some(idx) { None } else { Some(idx) };
+                                })
+                            >
+                                <span class=styles::eventTitle>{event.title.clone()}</span>
+                                <span class=styles::eventRef>{own}</span>
+                            </button>
+                            <Show when=move || open.get() == Some(idx)>
+                                <GospelAccounts event=event exclude=gospel on_jump=on_jump/>
+                            </Show>
+                        </div>
+                    }
+                }).collect_view()}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,191 @@
+//! Listen mode - reads the chapter aloud verse by verse.
+
+use leptos::prelude::*;
+use revelation_bible::Verse;
+
+use crate::{audio::ListenSource, haptic, reference::Reference, speech, state::AppState};
+
+stylance::import_crate_style!(styles, "src/styles/listen.module.css");
+
+/// Speech playback state shared by the bar controls.
+#[derive(Clone, Copy)]
+struct Narration {
+    verses:   Signal<Vec<Verse>>,
+    spoken:   RwSignal<Option<i16>>,
+    index:    RwSignal<usize>,
+    playing:  RwSignal<bool>,
+    rate:     RwSignal<f64>,
+    /// Incremented on every utterance so stale callbacks are ignored.
+    token:    StoredValue<u32>,
+    /// Set while waiting for the next chapter to load.
+    awaiting: StoredValue<bool>,
+    advance:  Callback<(), bool>
+}
+
+impl Narration {
+    /// Speaks the verse at `idx` and continues with the following ones.
+    fn play_from(self, idx: usize) {
+        let verse = self
+            .verses
+            .with_untracked(|verses| verses.get(idx).cloned());
+        let Some(verse) = verse else {
+            // Chapter finished: move on like the "next" chapter button does
+            self.spoken.set(None);
+            if self.advance.run(()) {
+                self.awaiting.set_value(true);
+            } else {
+                self.stop();
+            }
+            return;
+        };
+
+        let token = self.token.get_value().wrapping_add(1);
+        self.token.set_value(token);
+        self.index.set(idx);
+        self.spoken.set(Some(verse.verse));
+        self.playing.set(true);
+
+        speech::cancel();
+        speech::speak(&verse.text, self.rate.get_untracked(), move || {
+            if self.token.get_value() == token && self.playing.get_untracked() {
+                self.play_from(idx + 1);
+            }
+        });
+    }
+
+    /// Stops speaking but keeps the position.
+    fn pause(self) {
+        self.token.update_value(|t| *t = t.wrapping_add(1));
+        self.playing.set(false);
+        speech::cancel();
+    }
+
+    /// Stops speaking and clears the highlight.
+    fn stop(self) {
+        self.pause();
+        self.awaiting.set_value(false);
+        self.spoken.set(None);
+    }
+
+    /// Jumps by `delta` verses within the chapter.
+    fn skip(self, delta: isize) {
+        let count = self.verses.with_untracked(Vec::len);
+        let idx = self
+            .index
+            .get_untracked()
+            .saturating_add_signed(delta)
+            .min(count.saturating_sub(1));
+        self.play_from(idx);
+    }
+}
+
+/// Playback bar for listen mode.
+#[must_use]
+#[component]
+pub fn ListenBar(
+    /// Verses of the current chapter.
+    #[prop(into)]
+    verses: Signal<Vec<Verse>>,
+    /// Verse being read, highlighted by the reader.
+    spoken: RwSignal<Option<i16>>,
+    /// Moves to the next chapter, returns `false` at the end of the Bible.
+    advance: impl Fn() -> bool + Send + Sync + 'static,
+    /// Closes listen mode.
+    on_close: impl Fn() + Copy + Send + Sync + 'static
+) -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let narration = Narration {
+        verses,
+        spoken,
+        index: RwSignal::new(0),
+        playing: RwSignal::new(false),
+        rate: state.speech_rate,
+        token: StoredValue::new(0),
+        awaiting: StoredValue::new(false),
+        advance: Callback::new(move |()| advance())
+    };
+
+    // Start the next chapter once its verses are loaded, stop on manual navigation
+    Effect::new(move |prev: Option<(i16, i16)>| {
+        let current = (state.current_book.get(), state.current_chapter.get());
+        let loaded = verses.with(|v| v.first().map(|v| (v.book_id, v.chapter)));
+
+        if narration.awaiting.get_value() {
+            if loaded == Some(current) {
+                narration.awaiting.set_value(false);
+                narration.play_from(0);
+            }
+        } else if prev.is_some_and(|prev| prev != current) {
+            narration.stop();
+            narration.index.set(0);
+        }
+
+        current
+    });
+
+    on_cleanup(move || {
+        speech::cancel();
+        spoken.set(None);
+    });
+
+    let label = move || {
+        Reference::at(
+            state.current_book.get(),
+            state.current_chapter.get(),
+            spoken.get()
+        )
+        .to_string()
+    };
+
+    let cycle_rate = move |_| {
+        haptic::tap();
+        narration.rate.update(|rate| {
+            let idx = speech::RATES
+                .iter()
+                .position(|r| (r - *rate).abs() < f64::EPSILON)
+                .unwrap_or(0);
+            *rate = speech::RATES[(idx + 1) % speech::RATES.len()];
+        });
+    };
+
+    view! {
+        <div class=styles::bar>
+            <span class=styles::label>{label}</span>
+            <button
+                class=styles::control
+                aria-label="Предыдущий стих"
+                on:click=move |_| { haptic::tap(); narration.skip(-1); }
+            >"⏮"</button>
+            <button
+                class=format!("{} {}", styles::control, styles::play)
+                aria-label=move || if narration.playing.get() { "Пауза" } else { "Слушать" }
+                on:click=move |_| {
+                    haptic::tap();
+                    if narration.playing.get_untracked() {
+                        narration.pause();
+                    } else {
+                        narration.play_from(narration.index.get_untracked());
+                    }
+                }
+            >{move || if narration.playing.get() { "⏸" } else { "▶" }}</button>
+            <button
+                class=styles::control
+                aria-label="Следующий стих"
+                on:click=move |_| { haptic::tap(); narration.skip(1); }
+            >"⏭"</button>
+            <button class=styles::rate on:click=cycle_rate>
+                {move || format!("{}×", narration.rate.get())}
+            </button>
+            <button
+                class=styles::rate
+                aria-label="Аудиозапись"
+                on:click=move |_| state.listen_source.set(ListenSource::Audio)
+            >"Запись"</button>
+            <button
+                class=styles::close
+                aria-label="Закрыть"
+                on:click=move |_| { narration.stop(); on_close(); }
+            >"✕"</button>
+        </div>
+    }
+}
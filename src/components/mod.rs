@@ -1,7 +1,33 @@
+mod audio_bar;
+mod command_palette;
+mod commentary_panel;
+mod find_bar;
+mod gospel_accounts;
+mod listen_bar;
 mod loading;
 mod nav;
+mod parallel_chapter;
+mod passage_list;
+mod reference_jump;
+mod selection_bar;
+mod shortcuts;
+mod symphony_sheet;
 mod verse_card;
+mod verse_image_sheet;
 
+pub use audio_bar::*;
+pub use command_palette::*;
+pub use commentary_panel::*;
+pub use find_bar::*;
+pub use gospel_accounts::*;
+pub use listen_bar::*;
 pub use loading::*;
 pub use nav::{BottomNav, Header, Sidebar};
+pub use parallel_chapter::*;
+pub use passage_list::*;
+pub use reference_jump::*;
+pub use selection_bar::*;
+pub use shortcuts::*;
+pub use symphony_sheet::*;
 pub use verse_card::*;
+pub use verse_image_sheet::*;
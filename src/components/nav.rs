@@ -3,6 +3,8 @@
 use leptos::{prelude::*, reactive::wrappers::read::Signal};
 use leptos_router::{components::A, hooks::use_location};
 
+use crate::bible::get_book_category_var;
+
 #[allow(dead_code)]
 mod styles {
     stylance::import_crate_style!(pub nav, "src/styles/nav.module.css");
@@ -248,19 +250,3 @@ fn ProfileIcon() -> impl IntoView {
         </svg>
     }
 }
-
-/// Get book category CSS variable.
-const fn get_book_category_var(book_id: i16) -> &'static str {
-    match book_id {
-        6..=17 => "var(--cat-history)",
-        18..=22 => "var(--cat-wisdom)",
-        23..=27 => "var(--cat-major-prophets)",
-        28..=39 => "var(--cat-minor-prophets)",
-        40..=43 => "var(--cat-gospels)",
-        44 => "var(--cat-acts)",
-        45..=57 => "var(--cat-paul)",
-        58..=65 => "var(--cat-general)",
-        66 => "var(--cat-revelation)",
-        _ => "var(--cat-torah)"
-    }
-}
@@ -0,0 +1,258 @@
+//! Parallel translations - a chapter shown in several translations at once.
+
+use std::collections::BTreeSet;
+
+use leptos::prelude::*;
+use revelation_bible::Verse;
+
+use super::HighlightedText;
+use crate::{
+    bible::{BibleCache, Translation},
+    haptic,
+    state::AppState,
+    versification::{VerseKey, Versification}
+};
+
+stylance::import_crate_style!(styles, "src/styles/parallel.module.css");
+
+/// Row of the parallel view.
+#[derive(Debug, Clone)]
+enum Row {
+    /// Synodal verse with its counterparts.
+    Verse(Verse),
+    /// Verse of another numbering that no Synodal verse maps to.
+    Unmatched(Versification, VerseKey)
+}
+
+/// Verses of the shown translations that no Synodal verse of the chapter
+/// maps to, each with the Synodal verse it follows.
+fn unmatched(
+    verses: &[Verse],
+    shown: &[Translation],
+    bibles: &[(Translation, BibleCache)]
+) -> Vec<(i16, Versification, VerseKey)> {
+    let Some(first) = verses.first() else {
+        return Vec::new();
+    };
+    let mut rows = Vec::new();
+    for (translation, bible) in bibles.iter().filter(|(t, _)| shown.contains(t)) {
+        let scheme = translation.versification();
+        let targets: BTreeSet<VerseKey> = verses
+            .iter()
+            .filter_map(|v| Versification::Synodal.map(scheme, (v.book_id, v.chapter, v.verse)))
+            .collect();
+        let chapters: BTreeSet<(i16, i16)> = targets.iter().map(|&(b, c, _)| (b, c)).collect();
+        for (book, chapter) in chapters {
+            for verse in bible.get_chapter(book, chapter).unwrap_or_default() {
+                let key = (book, chapter, verse.verse);
+                let anchor = scheme.map(Versification::Synodal, key);
+                if targets.contains(&key) || rows.iter().any(|&(_, s, k)| s == scheme && k == key)
+                {
+                    continue;
+                }
+                // Verses belonging to another Synodal chapter are shown there
+                if let Some((_, synodal_chapter, anchor)) = anchor
+                    && synodal_chapter == first.chapter
+                {
+                    rows.push((anchor, scheme, key));
+                }
+            }
+        }
+    }
+    rows
+}
+
+/// Chapter with parallel translations aligned verse by verse.
+///
+/// Every row holds a Synodal verse and its counterparts mapped through the
+/// versification of each translation, so all columns share one scroll and
+/// stay in sync. Verses of a translation that no Synodal verse maps to get
+/// rows of their own after the verse they follow. On narrow screens rows
+/// are stacked, interleaving the translations under each verse.
+#[must_use]
+#[component]
+pub fn ParallelChapter(
+    /// Synodal verses of the chapter.
+    verses: Vec<Verse>,
+    /// Selected verse numbers.
+    selected: RwSignal<Vec<i16>>,
+    /// Verse highlighted after navigation.
+    focus_verse: RwSignal<Option<i16>>,
+    /// Verse being read aloud.
+    spoken: RwSignal<Option<i16>>,
+    /// Called when a verse is tapped.
+    on_toggle: Callback<i16>
+) -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let columns = move || state.parallel.with(Vec::len) + 1;
+    let chapter = verses.first().map_or(1, |v| v.chapter);
+
+    let is_loaded = move |translation: Translation| {
+        state
+            .parallel_bibles
+            .with(|bibles| bibles.iter().any(|(t, _)| *t == translation))
+    };
+
+    // Synodal verses interleaved with unmatched ones, which follow their anchor
+    let rows = move || {
+        let extra = state.parallel.with(|shown| {
+            state
+                .parallel_bibles
+                .with(|bibles| unmatched(&verses, shown, bibles))
+        });
+        let mut rows: Vec<(i16, bool, Row)> = verses
+            .iter()
+            .map(|v| (v.verse, false, Row::Verse(v.clone())))
+            .chain(
+                extra
+                    .into_iter()
+                    .map(|(anchor, scheme, key)| (anchor, true, Row::Unmatched(scheme, key)))
+            )
+            .collect();
+        rows.sort_by_key(|(anchor, extra, row)| {
+            let own = match row {
+                Row::Verse(v) => v.verse,
+                Row::Unmatched(_, key) => key.2
+            };
+            (*anchor, *extra, own)
+        });
+        rows.into_iter().map(|(_, _, row)| row).collect::<Vec<_>>()
+    };
+
+    view! {
+        <div class=styles::parallel style=move || format!("--columns: {}", columns())>
+            <div class=styles::heading>
+                <span>{Translation::Synodal.label()}</span>
+                {move || state.parallel.get().into_iter().map(|t| view! {
+                    <span title=t.name()>
+                        {t.label()}
+                        {move || (!is_loaded(t)).then_some(" · загрузка")}
+                    </span>
+                }).collect_view()}
+            </div>
+            {move || rows().into_iter().map(|row| match row {
+                Row::Verse(v) => {
+                    let key = (v.book_id, v.chapter, v.verse);
+                    let num = v.verse;
+                    view! {
+                        <div
+                            id=format!("v{num}")
+                            class=move || format!(
+                                "{} {} {} {}",
+                                styles::row,
+                                if focus_verse.get() == Some(num) { styles::rowFocus } else { "" },
+                                if spoken.get() == Some(num) { styles::rowSpoken } else { "" },
+                                if selected.with(|s| s.contains(&num)) { styles::rowSelected } else { "" }
+                            )
+                            on:click=move |_| on_toggle.run(num)
+                        >
+                            <p class=styles::cell>
+                                <span class=styles::label>{Translation::Synodal.label()}</span>
+                                <sup class=styles::verseNum>{num}</sup>
+                                <HighlightedText verse=num text=v.text/>
+                            </p>
+                            {move || state.parallel.get().into_iter().map(|t| view! {
+                                <ParallelCell
+                                    translation=t
+                                    from=Versification::Synodal
+                                    key=key
+                                    chapter=chapter
+                                />
+                            }).collect_view()}
+                        </div>
+                    }
+                    .into_any()
+                }
+                Row::Unmatched(scheme, key) => view! {
+                    <div class=styles::row>
+                        <p class=styles::cell>
+                            <span class=styles::label>{Translation::Synodal.label()}</span>
+                        </p>
+                        {move || state.parallel.get().into_iter().map(|t| view! {
+                            <ParallelCell translation=t from=scheme key=key chapter=chapter/>
+                        }).collect_view()}
+                    </div>
+                }
+                .into_any()
+            }).collect_view()}
+        </div>
+    }
+}
+
+/// Counterpart of a verse in another translation.
+#[component]
+fn ParallelCell(
+    translation: Translation,
+    /// Numbering of `key`.
+    from: Versification,
+    key: VerseKey,
+    /// Synodal chapter being read.
+    chapter: i16
+) -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let target = from.map(translation.versification(), key);
+
+    let text = move || {
+        let (book, chapter, verse) = target?;
+        state.parallel_bibles.with(|bibles| {
+            let (_, bible) = bibles.iter().find(|(t, _)| *t == translation)?;
+            bible
+                .get_verse_text(book, chapter, verse)
+                .map(str::to_string)
+        })
+    };
+
+    // Show the chapter too when the numbering moved to another one
+    let number = target.map(|(_, target_chapter, verse)| {
+        if target_chapter == chapter {
+            verse.to_string()
+        } else {
+            format!("{target_chapter}:{verse}")
+        }
+    });
+
+    view! {
+        <p class=styles::cell lang="en">
+            <span class=styles::label>{translation.label()}</span>
+            {number.map(|n| view! { <sup class=styles::verseNum>{n}</sup> })}
+            {move || text().unwrap_or_default()}
+        </p>
+    }
+}
+
+/// Choice of translations shown next to the Synodal text.
+#[must_use]
+#[component]
+pub fn ParallelPicker() -> impl IntoView {
+    let state = expect_context::<AppState>();
+
+    let toggle = move |translation: Translation| {
+        haptic::tap();
+        state.parallel.update(|selected| {
+            if let Some(idx) = selected.iter().position(|t| *t == translation) {
+                selected.remove(idx);
+            } else {
+                selected.push(translation);
+            }
+        });
+    };
+
+    view! {
+        <div class=styles::picker>
+            <span class=styles::pickerTitle>"Параллельно"</span>
+            {Translation::PARALLEL.into_iter().map(|t| view! {
+                <button
+                    class=move || if state.parallel.with(|s| s.contains(&t)) {
+                        format!("{} {}", styles::chip, styles::chipActive)
+                    } else {
+                        styles::chip.to_string()
+                    }
+                    title=t.name()
+                    on:click=move |_| toggle(t)
+                >
+                    {t.label()}
+                </button>
+            }).collect_view()}
+        </div>
+    }
+}
@@ -0,0 +1,48 @@
+//! Passages read from the cached Bible with links into the reader.
+
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use super::{Loading, VerseList};
+use crate::{dictionary::resolve, reference::Reference, state::AppState};
+
+stylance::import_crate_style!(styles, "src/styles/passages.module.css");
+
+/// Passages as cards, each titled with a link to the verse in the reader.
+#[must_use]
+#[component]
+pub fn PassageList(references: Vec<Reference>) -> impl IntoView {
+    let state = expect_context::<AppState>();
+
+    move || {
+        state.bible.with(|bible| {
+            bible.as_ref().map_or_else(
+                || view! { <Loading/> }.into_any(),
+                |bible| {
+                    resolve(bible, &references)
+                        .into_iter()
+                        .map(|(reference, verses)| {
+                            view! {
+                                <div class=styles::passage>
+                                    <A
+                                        href=format!(
+                                            "/bible/{}/{}?verse={}",
+                                            reference.book_id,
+                                            reference.chapter,
+                                            reference.verse.unwrap_or(1)
+                                        )
+                                        attr:class=styles::title
+                                    >
+                                        {reference.to_string()}
+                                    </A>
+                                    <VerseList verses=verses/>
+                                </div>
+                            }
+                        })
+                        .collect_view()
+                        .into_any()
+                }
+            )
+        })
+    }
+}
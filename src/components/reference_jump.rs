@@ -0,0 +1,181 @@
+//! Reference field - type `Рим 8 28` or `ps 23` to go straight there.
+
+use leptos::{ev, html, prelude::*};
+
+use crate::{
+    bible::{book_abbreviation, book_name},
+    reference::{Reference, match_books, recent_references, remember_reference},
+    state::AppState
+};
+
+stylance::import_crate_style!(styles, "src/styles/reference_jump.module.css");
+
+/// Maximum number of suggestions shown.
+const MAX_ITEMS: usize = 8;
+
+/// Completion offered under the field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Suggestion {
+    /// A full reference, opened on pick.
+    Passage(Reference),
+    /// A book, completed into the field on pick.
+    Book(i16)
+}
+
+impl Suggestion {
+    fn label(&self) -> String {
+        match self {
+            Self::Passage(r) => {
+                let name = book_name(r.book_id).unwrap_or_default();
+                let location = r.to_string();
+                let location = location.split_once(' ').map_or("", |(_, loc)| loc);
+                format!("{name} {location}")
+            }
+            Self::Book(id) => book_name(*id).unwrap_or_default().to_string()
+        }
+    }
+
+    fn hint(&self, recent: bool) -> &'static str {
+        match self {
+            Self::Passage(_) if recent => "Недавнее",
+            Self::Passage(_) => "Перейти",
+            Self::Book(id) => book_abbreviation(*id).unwrap_or_default()
+        }
+    }
+}
+
+/// Builds suggestions for the typed text; recent references when empty.
+fn suggest(query: &str) -> Vec<Suggestion> {
+    let query = query.trim();
+    if query.is_empty() {
+        return recent_references()
+            .into_iter()
+            .map(Suggestion::Passage)
+            .collect();
+    }
+    if let Some(reference) = Reference::parse(query) {
+        return vec![Suggestion::Passage(reference)];
+    }
+    let mut items: Vec<_> = match_books(query)
+        .into_iter()
+        .map(Suggestion::Book)
+        .collect();
+    items.truncate(MAX_ITEMS);
+    items
+}
+
+/// Header field that parses a reference, checks it against the loaded
+/// translation and opens it.
+#[must_use]
+#[component]
+pub fn ReferenceJump(
+    /// Opens a checked reference.
+    on_jump: Callback<Reference>,
+    /// Closes the field.
+    on_close: impl Fn() + Copy + Send + Sync + 'static
+) -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let query = RwSignal::new(String::new());
+    let selected = RwSignal::new(0_usize);
+    let error = RwSignal::new(None::<String>);
+    let input_ref: NodeRef<html::Input> = NodeRef::new();
+
+    let items = Memo::new(move |_| suggest(&query.get()));
+
+    Effect::new(move |_| {
+        if let Some(input) = input_ref.get() {
+            let _ = input.focus();
+        }
+    });
+
+    let pick = move |suggestion: Suggestion| match suggestion {
+        Suggestion::Passage(reference) => {
+            let checked = state.bible.with_untracked(|bible| {
+                bible
+                    .as_ref()
+                    .map_or(Ok(()), |b| b.check_reference(&reference))
+            });
+            match checked {
+                Ok(()) => {
+                    remember_reference(reference);
+                    on_jump.run(reference);
+                }
+                Err(message) => error.set(Some(message))
+            }
+        }
+        Suggestion::Book(id) => {
+            query.set(format!("{} ", book_abbreviation(id).unwrap_or_default()));
+            selected.set(0);
+            if let Some(input) = input_ref.get_untracked() {
+                let _ = input.focus();
+            }
+        }
+    };
+
+    let on_keydown = move |ev: ev::KeyboardEvent| match ev.key().as_str() {
+        "Escape" => on_close(),
+        "ArrowDown" => {
+            ev.prevent_default();
+            let count = items.with_untracked(Vec::len);
+            selected.update(|i| *i = (*i + 1).min(count.saturating_sub(1)));
+        }
+        "ArrowUp" => {
+            ev.prevent_default();
+            selected.update(|i| *i = i.saturating_sub(1));
+        }
+        "Enter" | "Tab" => {
+            let item = items.with_untracked(|items| items.get(selected.get_untracked()).copied());
+            if let Some(item) = item {
+                ev.prevent_default();
+                pick(item);
+            }
+        }
+        _ => {}
+    };
+
+    view! {
+        <div class=styles::jump>
+            <input
+                node_ref=input_ref
+                type="text"
+                class=styles::input
+                placeholder="Рим 8 28"
+                aria-label="Перейти к месту"
+                autocomplete="off"
+                prop:value=query
+                on:input=move |ev| {
+                    query.set(event_target_value(&ev));
+                    selected.set(0);
+                    error.set(None);
+                }
+                on:keydown=on_keydown
+            />
+            <button class=styles::close aria-label="Закрыть" on:click=move |_| on_close()>
+                "✕"
+            </button>
+            <div class=styles::dropdown>
+                {move || error.get().map(|message| view! { <p class=styles::error>{message}</p> })}
+                <ul class=styles::list>
+                    {move || {
+                        let recent = query.with(|q| q.trim().is_empty());
+                        items.get().into_iter().enumerate().map(|(idx, item)| view! {
+                            <li
+                                class=move || if selected.get() == idx {
+                                    format!("{} {}", styles::item, styles::itemActive)
+                                } else {
+                                    styles::item.to_string()
+                                }
+                                on:mouseenter=move |_| selected.set(idx)
+                                on:mousedown=|ev| ev.prevent_default()
+                                on:click=move |_| pick(item)
+                            >
+                                <span class=styles::itemLabel>{item.label()}</span>
+                                <span class=styles::itemHint>{item.hint(recent)}</span>
+                            </li>
+                        }).collect::<Vec<_>>()
+                    }}
+                </ul>
+            </div>
+        </div>
+    }
+}
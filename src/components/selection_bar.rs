@@ -0,0 +1,179 @@
+//! Action bar for selected verses - copy and share with a reference.
+
+use std::time::Duration;
+
+use leptos::prelude::*;
+use leptos_router::{NavigateOptions, hooks::use_navigate, params::ParamsMap};
+use revelation_bible::Verse;
+use wasm_bindgen_futures::spawn_local;
+
+use super::VerseImageSheet;
+use crate::{
+    daily, haptic,
+    maps::{Place, atlas},
+    memorize::{MemoryCard, MemoryDeck},
+    reference::Reference,
+    share::{self, ShareOutcome, citation, format_passage},
+    state::AppState
+};
+
+stylance::import_crate_style!(styles, "src/styles/selection.module.css");
+
+/// Link to the print page for verses in a row; the page takes one range,
+/// so a selection with gaps has none.
+fn print_href(verses: &[Verse]) -> Option<String> {
+    let (first, last) = (verses.first()?, verses.last()?);
+    if verses.windows(2).any(|w| w[1].verse != w[0].verse + 1) {
+        return None;
+    }
+    let reference = Reference {
+        book_id:     first.book_id,
+        chapter:     first.chapter,
+        verse:       Some(first.verse),
+        end_chapter: last.chapter,
+        end_verse:   Some(last.verse)
+    };
+    let query: ParamsMap = std::iter::once(("ref", reference.to_string())).collect();
+    Some(format!("/print{}", query.to_query_string()))
+}
+
+/// Floating bar shown while verses are selected in the reader.
+#[must_use]
+#[component]
+pub fn SelectionBar(
+    /// Selected verses in reading order.
+    #[prop(into)]
+    selection: Signal<Vec<Verse>>,
+    /// Clears the selection.
+    on_clear: impl Fn() + Copy + Send + Sync + 'static
+) -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let (status, set_status) = signal::<Option<&'static str>>(None);
+    let image_open = RwSignal::new(false);
+
+    let passage =
+        move || format_passage(&selection.get_untracked(), state.copy_style.get_untracked());
+
+    let finish = move |label: &'static str| {
+        haptic::success();
+        set_status.set(Some(label));
+        set_timeout(
+            move || {
+                set_status.set(None);
+                on_clear();
+            },
+            Duration::from_millis(900)
+        );
+    };
+
+    let fail = move |label: &'static str, error: String| {
+        haptic::error();
+        web_sys::console::error_1(&error.into());
+        set_status.set(Some(label));
+        set_timeout(move || set_status.set(None), Duration::from_millis(1500));
+    };
+
+    let on_copy = move |_| {
+        let Some(text) = passage() else {
+            return;
+        };
+        spawn_local(async move {
+            match share::copy(&text).await {
+                Ok(()) => finish("Скопировано"),
+                Err(e) => fail("Не удалось скопировать", e)
+            }
+        });
+    };
+
+    let on_share = move |_| {
+        let Some(text) = passage() else {
+            return;
+        };
+        spawn_local(async move {
+            match share::share(&text).await {
+                Ok(ShareOutcome::Shared) => on_clear(),
+                Ok(ShareOutcome::Copied) => finish("Скопировано"),
+                Ok(ShareOutcome::Cancelled) => {}
+                Err(e) => fail("Не удалось поделиться", e)
+            }
+        });
+    };
+
+    let on_memorize = move |_| {
+        let Some(card) = MemoryCard::from_verses(&selection.get_untracked(), daily::today())
+        else {
+            return;
+        };
+        finish(if MemoryDeck::add(card) {
+            "Добавлено в заучивание"
+        } else {
+            "Уже в заучивании"
+        });
+    };
+
+    let navigate = use_navigate();
+    let printable = move || selection.with(|verses| print_href(verses).is_some());
+    let on_print = move |_| {
+        if let Some(href) = print_href(&selection.get_untracked()) {
+            navigate(&href, NavigateOptions::default());
+        }
+    };
+
+    view! {
+        <div class=styles::bar>
+            <span class=styles::reference>
+                {move || status.get().map_or_else(
+                    || citation(&selection.get()).unwrap_or_default(),
+                    ToString::to_string
+                )}
+            </span>
+            <button class=styles::action on:click=on_copy>"Копировать"</button>
+            <button class=styles::action on:click=move |_| image_open.set(true)>"Картинка"</button>
+            <button class=styles::action on:click=on_memorize>"Выучить"</button>
+            <button
+                class=styles::action
+                disabled=move || !printable()
+                title=move || (!printable()).then_some("Печатаются только стихи подряд")
+                on:click=on_print
+            >
+                "Печать"
+            </button>
+            <ShowOnMap selection=selection/>
+            <Show when=share::is_share_supported>
+                <button class=styles::action on:click=on_share>"Поделиться"</button>
+            </Show>
+            <button class=styles::close on:click=move |_| on_clear() aria-label="Снять выделение">
+                "✕"
+            </button>
+        </div>
+        <Show when=move || image_open.get()>
+            <VerseImageSheet selection=selection open=image_open/>
+        </Show>
+    }
+}
+
+/// Opens the map at the first known place named in the selected verses.
+#[component]
+fn ShowOnMap(selection: Signal<Vec<Verse>>) -> impl IntoView {
+    let navigate = use_navigate();
+    let href = move || {
+        selection.with(|verses| {
+            verses
+                .iter()
+                .find_map(|v| atlas().place_in(&v.text))
+                .and_then(Place::href)
+        })
+    };
+
+    move || {
+        href().map(|href| {
+            let navigate = navigate.clone();
+            view! {
+                <button
+                    class=styles::action
+                    on:click=move |_| navigate(&href, NavigateOptions::default())
+                >"На карте"</button>
+            }
+        })
+    }
+}
@@ -0,0 +1,137 @@
+//! Global keyboard shortcuts for desktop navigation.
+
+use leptos::{ev, prelude::*};
+use leptos_router::{
+    NavigateOptions,
+    hooks::{use_location, use_navigate}
+};
+use revelation_ui::theme::{Theme, use_theme};
+use wasm_bindgen::JsCast;
+
+use super::CommandPalette;
+use crate::{
+    bible::{BibleCache, ChapterStep, adjacent_chapter},
+    state::AppState
+};
+
+/// Registers global shortcuts and hosts the command palette.
+///
+/// - `Ctrl+K` / `Cmd+K` - command palette
+/// - `Ctrl+F` / `Cmd+F` - find in chapter
+/// - `←` / `→` or `[` / `]` - previous/next chapter in the reader
+/// - `B` - books panel
+/// - `/` - search
+/// - `T` - switch theme
+#[component]
+pub fn Shortcuts() -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let theme = use_theme().theme;
+    let location = use_location();
+    let navigate = use_navigate();
+    let palette_open = RwSignal::new(false);
+
+    let step_chapter = move |step: ChapterStep| {
+        let books = state
+            .bible
+            .with_untracked(|bible| bible.as_ref().map(BibleCache::get_books))
+            .unwrap_or_default();
+        if let Some((book, chapter)) = adjacent_chapter(
+            &books,
+            state.current_book.get_untracked(),
+            state.current_chapter.get_untracked(),
+            step
+        ) {
+            state.current_book.set(book);
+            state.current_chapter.set(chapter);
+        }
+    };
+
+    let handle = window_event_listener(ev::keydown, move |ev| {
+        // Physical key codes keep shortcuts working on the Russian layout
+        let code = ev.code();
+
+        if (ev.ctrl_key() || ev.meta_key()) && code == "KeyK" {
+            ev.prevent_default();
+            palette_open.update(|v| *v = !*v);
+            return;
+        }
+
+        if (ev.ctrl_key() || ev.meta_key())
+            && code == "KeyF"
+            && location.pathname.get_untracked().starts_with("/bible")
+        {
+            ev.prevent_default();
+            state.find_open.set(true);
+            return;
+        }
+
+        if ev.ctrl_key()
+            || ev.meta_key()
+            || ev.alt_key()
+            || palette_open.get_untracked()
+            || is_editable(&ev)
+        {
+            return;
+        }
+
+        // The presentation handles its own keys
+        if location.pathname.get_untracked().starts_with("/present") {
+            return;
+        }
+
+        let in_reader = location.pathname.get_untracked().starts_with("/bible");
+
+        match code.as_str() {
+            "ArrowLeft" | "BracketLeft" if in_reader => step_chapter(ChapterStep::Prev),
+            "ArrowRight" | "BracketRight" if in_reader => step_chapter(ChapterStep::Next),
+            "KeyB" => {
+                state.books_open.set(true);
+                if !in_reader {
+                    navigate("/bible", NavigateOptions::default());
+                }
+            }
+            "Slash" => {
+                ev.prevent_default();
+                if location.pathname.get_untracked() == "/search" {
+                    focus_search_input();
+                } else {
+                    navigate("/search", NavigateOptions::default());
+                }
+            }
+            "KeyT" => theme.update(|t| {
+                *t = match *t {
+                    Theme::Light => Theme::Sepia,
+                    Theme::Sepia => Theme::Dark,
+                    Theme::Dark => Theme::Light
+                };
+            }),
+            _ => {}
+        }
+    });
+    on_cleanup(move || handle.remove());
+
+    view! {
+        <Show when=move || palette_open.get()>
+            <CommandPalette open=palette_open/>
+        </Show>
+    }
+}
+
+/// Checks whether the event comes from a text field.
+fn is_editable(ev: &web_sys::KeyboardEvent) -> bool {
+    ev.target()
+        .and_then(|t| t.dyn_into::<web_sys::HtmlElement>().ok())
+        .is_some_and(|el| {
+            let tag = el.tag_name();
+            tag == "INPUT" || tag == "TEXTAREA" || tag == "SELECT" || el.is_content_editable()
+        })
+}
+
+/// Focuses the search field on the current page.
+fn focus_search_input() {
+    if let Ok(Some(el)) = document().query_selector("input[type=search]")
+        && let Ok(input) = el.dyn_into::<web_sys::HtmlElement>()
+    {
+        let _ = input.focus();
+    }
+}
@@ -0,0 +1,149 @@
+//! Bottom sheet with the symphony of a word picked in the reader.
+
+use std::collections::BTreeMap;
+
+use leptos::prelude::*;
+use revelation_bible::Verse;
+
+use super::Loading;
+use crate::{api, bible::book_abbreviation, search, state::AppState};
+
+stylance::import_crate_style!(styles, "src/styles/symphony.module.css");
+
+/// Verses rendered at once; the per-book filter narrows the rest.
+const MAX_SHOWN: usize = 200;
+
+/// Symphony of a word: occurrences by book and the verses themselves.
+///
+/// Uses the server symphony and falls back to the offline concordance when
+/// the server can't be reached.
+#[must_use]
+#[component]
+pub fn SymphonySheet(
+    /// Word to look up.
+    word: String,
+    /// Closes the sheet.
+    on_close: impl Fn() + Copy + Send + Sync + 'static,
+    /// Opens a verse as `(book, chapter, verse)`.
+    on_jump: Callback<(i16, i16, i16)>
+) -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let word = StoredValue::new(word);
+    let book_filter = RwSignal::new(None::<i16>);
+
+    let results = LocalResource::new(move || {
+        let word = word.get_value();
+        async move {
+            match api::get_symphony(&word).await {
+                Ok(response) => Ok(response.verses.into_iter().map(|r| r.verse).collect()),
+                Err(e) if e.starts_with("Network error") => Ok(state
+                    .bible
+                    .with_untracked(|bible| bible.as_ref().map(|b| search::concordance(b, &word)))
+                    .unwrap_or_default()),
+                Err(e) => Err(e)
+            }
+        }
+    });
+
+    view! {
+        <div class=styles::overlay on:click=move |_| on_close()>
+            <div class=styles::sheet on:click=|ev| ev.stop_propagation()>
+                <div class=styles::head>
+                    <h2 class=styles::word>{word.get_value()}</h2>
+                    <button class=styles::close aria-label="Закрыть" on:click=move |_| on_close()>
+                        "✕"
+                    </button>
+                </div>
+                <Suspense fallback=|| view! { <Loading/> }>
+                    {move || results.get().map(|result| result.map_or_else(
+                        |_| view! {
+                            <p class=styles::empty>"Не удалось загрузить симфонию"</p>
+                        }.into_any(),
+                        |verses| view! {
+                            <SymphonyResults verses=verses book_filter=book_filter on_jump=on_jump/>
+                        }.into_any()
+                    ))}
+                </Suspense>
+            </div>
+        </div>
+    }
+}
+
+/// Per-book breakdown and the verse list.
+#[component]
+fn SymphonyResults(
+    verses: Vec<Verse>,
+    book_filter: RwSignal<Option<i16>>,
+    on_jump: Callback<(i16, i16, i16)>
+) -> impl IntoView {
+    if verses.is_empty() {
+        return view! { <p class=styles::empty>"Слово не найдено"</p> }.into_any();
+    }
+
+    let mut by_book = BTreeMap::<i16, usize>::new();
+    for verse in &verses {
+        *by_book.entry(verse.book_id).or_default() += 1;
+    }
+    let total = verses.len();
+    let truncated = total > MAX_SHOWN;
+    let verses = StoredValue::new(verses);
+
+    let shown = move || {
+        let filter = book_filter.get();
+        verses.with_value(|verses| {
+            verses
+                .iter()
+                .filter(|v| filter.is_none_or(|book| v.book_id == book))
+                .take(MAX_SHOWN)
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+    };
+
+    view! {
+        <p class=styles::total>{format!("Всего: {total}")}</p>
+        <div class=styles::books>
+            {by_book.into_iter().map(|(book_id, count)| view! {
+                <button
+                    class=move || if book_filter.get() == Some(book_id) {
+                        format!("{} {}", styles::book, styles::bookActive)
+                    } else {
+                        styles::book.to_string()
+                    }
+                    on:click=move |_| book_filter.update(|f| {
+                        *f = if *f == Some(book_id) { None } else { Some(book_id) };
+                    })
+                >
+                    {book_abbreviation(book_id).unwrap_or_default()}
+                    <span class=styles::count>{count}</span>
+                </button>
+            }).collect_view()}
+        </div>
+        <ul class=styles::list>
+            {move || shown().into_iter().map(|v| {
+                let target = (v.book_id, v.chapter, v.verse);
+                view! {
+                    <li>
+                        <button class=styles::verse on:click=move |_| on_jump.run(target)>
+                            <span class=styles::reference>
+                                {format!(
+                                    "{} {}:{}",
+                                    book_abbreviation(v.book_id).unwrap_or_default(),
+                                    v.chapter,
+                                    v.verse
+                                )}
+                            </span>
+                            <span class=styles::text>{v.text}</span>
+                        </button>
+                    </li>
+                }
+            }).collect_view()}
+        </ul>
+        <Show when=move || truncated && book_filter.get().is_none()>
+            <p class=styles::empty>
+                {format!("Показаны первые {MAX_SHOWN}, выберите книгу, чтобы увидеть остальные")}
+            </p>
+        </Show>
+    }
+    .into_any()
+}
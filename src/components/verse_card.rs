@@ -1,22 +1,49 @@
 //! Verse display components.
 
+use std::ops::Range;
+
 use leptos::prelude::*;
+use leptos_router::components::A;
 use revelation_bible::Verse;
 
-/// Verse card for search results.
+stylance::import_crate_style!(styles, "src/styles/verse_card.module.css");
+
+/// Verse card for search results, linking to the verse in the reader.
 #[must_use]
 #[component]
-pub fn VerseCard(verse: Verse, book_name: String) -> impl IntoView {
-    let text = verse.text;
+pub fn VerseCard(
+    verse: Verse,
+    book_name: String,
+    /// Byte ranges of the text to highlight, in order.
+    #[prop(optional)]
+    highlights: Vec<Range<usize>>
+) -> impl IntoView {
+    let href = format!(
+        "/bible/{}/{}?verse={}",
+        verse.book_id, verse.chapter, verse.verse
+    );
     let chapter = verse.chapter;
     let verse_num = verse.verse;
+    let text = verse.text;
+
+    let mut parts = Vec::with_capacity(highlights.len() * 2 + 1);
+    let mut pos = 0;
+    for range in highlights {
+        parts.push(text[pos..range.start].to_string().into_any());
+        parts.push(
+            view! { <mark class=styles::mark>{text[range.clone()].to_string()}</mark> }.into_any()
+        );
+        pos = range.end;
+    }
+    parts.push(text[pos..].to_string().into_any());
+
     view! {
-        <div class="card p-4">
-            <p class="verse-text">{text}</p>
+        <A href=href attr:class=format!("card p-4 {}", styles::card)>
+            <p class="verse-text">{parts}</p>
             <p class="text-sm mt-2" style="color: var(--color-text-muted)">
                 {book_name} " " {chapter} ":" {verse_num}
             </p>
-        </div>
+        </A>
     }
 }
 
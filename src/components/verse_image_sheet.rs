@@ -0,0 +1,144 @@
+//! Bottom sheet for generating verse image cards.
+
+use std::time::Duration;
+
+use leptos::{html, prelude::*};
+use revelation_bible::Verse;
+use revelation_ui::theme::{FontFamily, use_theme};
+use wasm_bindgen_futures::spawn_local;
+
+use crate::{
+    haptic,
+    share::{self, ShareOutcome, citation, passage_text},
+    verse_image::{self, Background, CardFormat, CardStyle, Typography}
+};
+
+stylance::import_crate_style!(styles, "src/styles/verse_image.module.css");
+
+/// Image card generator for selected verses.
+#[must_use]
+#[component]
+pub fn VerseImageSheet(
+    /// Verses to render.
+    #[prop(into)]
+    selection: Signal<Vec<Verse>>,
+    /// Sheet visibility.
+    open: RwSignal<bool>
+) -> impl IntoView {
+    let theme_font = use_theme().font_family.get_untracked();
+    let format = RwSignal::new(CardFormat::default());
+    let background = RwSignal::new(Background::default());
+    let typography = RwSignal::new(if theme_font == FontFamily::Sans {
+        Typography::Sans
+    } else {
+        Typography::Serif
+    });
+    let canvas_ref: NodeRef<html::Canvas> = NodeRef::new();
+    let error = RwSignal::new(false);
+
+    let reference = move || citation(&selection.get_untracked()).unwrap_or_default();
+    let filename = move || format!("{}.png", reference().replace([' ', ':', ','], "_"));
+
+    Effect::new(move |_| {
+        let style = CardStyle {
+            format:     format.get(),
+            background: background.get(),
+            typography: typography.get()
+        };
+        let verses = selection.get();
+        let Some(canvas) = canvas_ref.get() else {
+            return;
+        };
+        let reference = citation(&verses).unwrap_or_default();
+        if let Err(e) = verse_image::render(&canvas, &passage_text(&verses), &reference, style) {
+            web_sys::console::error_1(&e.into());
+        }
+    });
+
+    let on_download = move |_| {
+        if let Some(canvas) = canvas_ref.get_untracked() {
+            haptic::tap();
+            share::download_image(&canvas, &filename());
+        }
+    };
+
+    let on_share = move |_| {
+        let Some(canvas) = canvas_ref.get_untracked() else {
+            return;
+        };
+        let (name, text) = (filename(), reference());
+        spawn_local(async move {
+            match share::share_image(&canvas, &name, &text).await {
+                Ok(ShareOutcome::Shared) => haptic::success(),
+                Ok(ShareOutcome::Cancelled | ShareOutcome::Copied) => {}
+                Err(e) => {
+                    haptic::error();
+                    web_sys::console::error_1(&e.into());
+                    error.set(true);
+                    set_timeout(move || error.set(false), Duration::from_millis(1500));
+                }
+            }
+        });
+    };
+
+    view! {
+        <div class=styles::overlay on:click=move |_| open.set(false)>
+            <div class=styles::sheet on:click=|ev| ev.stop_propagation()>
+                <div class=styles::preview>
+                    <canvas node_ref=canvas_ref class=styles::canvas/>
+                </div>
+
+                <div class=styles::options>
+                    {CardFormat::ALL.into_iter().map(|value| view! {
+                        <button
+                            class=move || option_class(format.get() == value)
+                            on:click=move |_| format.set(value)
+                        >{value.label()}</button>
+                    }).collect::<Vec<_>>()}
+                </div>
+
+                <div class=styles::options>
+                    {Background::ALL.into_iter().map(|value| view! {
+                        <button
+                            class=move || if background.get() == value {
+                                format!("{} {}", styles::swatch, styles::swatchActive)
+                            } else { styles::swatch.to_string() }
+                            style:background=value.swatch()
+                            title=value.label()
+                            aria-label=value.label()
+                            on:click=move |_| background.set(value)
+                        />
+                    }).collect::<Vec<_>>()}
+                </div>
+
+                <div class=styles::options>
+                    {Typography::ALL.into_iter().map(|value| view! {
+                        <button
+                            class=move || option_class(typography.get() == value)
+                            on:click=move |_| typography.set(value)
+                        >{value.label()}</button>
+                    }).collect::<Vec<_>>()}
+                </div>
+
+                <div class=styles::actions>
+                    <button class=styles::action on:click=on_download>"Скачать PNG"</button>
+                    <Show when=share::is_file_share_supported>
+                        <button class=styles::action on:click=on_share>"Поделиться"</button>
+                    </Show>
+                </div>
+                <Show when=move || error.get()>
+                    <p class=styles::error role="alert">"Не удалось поделиться"</p>
+                </Show>
+            </div>
+        </div>
+    }
+}
+
+/// Class for a picker button.
+fn option_class(active: bool) -> String {
+    if active {
+        format!("{} {}", styles::option, styles::optionActive)
+    } else {
+        styles::option.to_string()
+    }
+}
@@ -0,0 +1,117 @@
+//! Daily reading computed on the device from a bundled schedule.
+//!
+//! The reading itself never needs the network; when online, a newer
+//! schedule published by the server replaces the stored one.
+
+use chrono::{Datelike, Local, NaiveDate};
+use gloo_storage::{LocalStorage, Storage};
+use revelation_bible::DailyReading;
+use serde::{Deserialize, Serialize};
+
+use crate::{api, bible::BibleCache, reference::Reference};
+
+/// Schedule shipped with the app.
+const BUNDLED_SCHEDULE: &str = include_str!("../assets/daily_schedule.json");
+const SCHEDULE_KEY: &str = "daily_schedule";
+
+/// Passages for every day of the year.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailySchedule {
+    /// Schedule revision, a refresh only replaces older ones.
+    pub version: u32,
+    /// References per day, starting on January 1.
+    pub days:    Vec<Vec<String>>
+}
+
+impl DailySchedule {
+    /// Returns the schedule shipped with the app.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bundled `daily_schedule.json` is malformed.
+    #[must_use]
+    pub fn bundled() -> Self {
+        serde_json::from_str(BUNDLED_SCHEDULE).expect("assets/daily_schedule.json is malformed")
+    }
+
+    /// Loads the stored schedule, unless the app ships a newer one.
+    #[must_use]
+    pub fn load() -> Self {
+        let bundled = Self::bundled();
+        match LocalStorage::get::<Self>(SCHEDULE_KEY) {
+            Ok(stored) if stored.version >= bundled.version && !stored.days.is_empty() => stored,
+            _ => bundled
+        }
+    }
+
+    /// Fetches the server's schedule and stores it if its version is newer.
+    ///
+    /// Returns the updated schedule, or `None` if nothing changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns error string if network request or JSON parsing fails.
+    pub async fn refresh(&self) -> Result<Option<Self>, String> {
+        let latest = api::get_daily_schedule().await?;
+        if latest.version <= self.version || latest.days.is_empty() {
+            return Ok(None);
+        }
+        LocalStorage::set(SCHEDULE_KEY, &latest).map_err(|e| format!("Storage error: {e}"))?;
+        Ok(Some(latest))
+    }
+
+    /// References scheduled for a date.
+    #[must_use]
+    pub fn references(&self, date: NaiveDate) -> Vec<Reference> {
+        if self.days.is_empty() {
+            return Vec::new();
+        }
+        let idx = usize::try_from(date.ordinal0()).unwrap_or(0) % self.days.len();
+        self.days[idx]
+            .iter()
+            .filter_map(|r| Reference::parse(r))
+            .collect()
+    }
+
+    /// Builds the reading for a date from the cached Bible.
+    #[must_use]
+    pub fn reading(&self, bible: &BibleCache, date: NaiveDate) -> Option<DailyReading> {
+        let verses: Vec<_> = self
+            .references(date)
+            .iter()
+            .flat_map(|reference| bible.get_passage(reference))
+            .collect();
+        if verses.is_empty() {
+            return None;
+        }
+        Some(DailyReading {
+            day_of_year: i16::try_from(date.ordinal()).unwrap_or_default(),
+            verses
+        })
+    }
+}
+
+/// Current local date.
+#[must_use]
+pub fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_references_parse() {
+        let schedule = DailySchedule::bundled();
+        assert!(schedule.days.len() >= 366);
+        for (day, references) in schedule.days.iter().enumerate() {
+            for reference in references {
+                assert!(
+                    Reference::parse(reference).is_some(),
+                    "day {day}: {reference}"
+                );
+            }
+        }
+    }
+}
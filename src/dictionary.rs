@@ -0,0 +1,217 @@
+//! Bible dictionary of people, places and terms, and the topical index.
+//!
+//! Both are shipped with the app, so they work without the network; the
+//! verses they point to are read from the cached Bible.
+
+use std::{collections::HashMap, sync::OnceLock};
+
+use revelation_bible::Verse;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bible::BibleCache,
+    reference::{Reference, parse_references},
+    search::normalize
+};
+
+/// Dictionary shipped with the app.
+const BUNDLED_DICTIONARY: &str = include_str!("../assets/dictionary.json");
+/// Topical index shipped with the app.
+const BUNDLED_TOPICS: &str = include_str!("../assets/topics.json");
+
+/// Kind of a dictionary entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EntryKind {
+    /// Person.
+    Person,
+    /// Geographic place.
+    Place,
+    /// Term or concept.
+    Term
+}
+
+impl EntryKind {
+    /// All kinds in display order.
+    pub const ALL: [Self; 3] = [Self::Person, Self::Place, Self::Term];
+
+    /// Plural label for tabs.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Person => "Люди",
+            Self::Place => "Места",
+            Self::Term => "Понятия"
+        }
+    }
+}
+
+/// Dictionary article.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DictionaryEntry {
+    /// Stable identifier used in links.
+    pub id:         String,
+    /// Headword.
+    pub name:       String,
+    /// Entry kind.
+    pub kind:       EntryKind,
+    /// Word forms linked in the Bible text.
+    #[serde(default)]
+    pub forms:      Vec<String>,
+    /// Short article.
+    pub summary:    String,
+    /// Key passages.
+    #[serde(default)]
+    pub references: Vec<String>
+}
+
+impl DictionaryEntry {
+    /// Key passages that parse as references.
+    #[must_use]
+    pub fn references(&self) -> Vec<Reference> {
+        parse_references(&self.references)
+    }
+}
+
+/// Topic of the topical index.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Topic {
+    /// Stable identifier.
+    pub id:         String,
+    /// Topic name.
+    pub name:       String,
+    /// Passages on the topic.
+    pub references: Vec<String>
+}
+
+impl Topic {
+    /// Passages that parse as references.
+    #[must_use]
+    pub fn references(&self) -> Vec<Reference> {
+        parse_references(&self.references)
+    }
+}
+
+#[derive(Deserialize)]
+struct DictionaryFile {
+    entries: Vec<DictionaryEntry>
+}
+
+#[derive(Deserialize)]
+struct TopicsFile {
+    topics: Vec<Topic>
+}
+
+/// Dictionary with a word form index.
+#[derive(Debug, Default)]
+pub struct Dictionary {
+    entries: Vec<DictionaryEntry>,
+    topics:  Vec<Topic>,
+    /// Normalized word form → entry index.
+    forms:   HashMap<String, usize>
+}
+
+impl Dictionary {
+    /// Parses the bundled dictionary and topics.
+    fn bundled() -> Self {
+        let entries = serde_json::from_str::<DictionaryFile>(BUNDLED_DICTIONARY)
+            .expect("assets/dictionary.json is malformed")
+            .entries;
+        let topics = serde_json::from_str::<TopicsFile>(BUNDLED_TOPICS)
+            .expect("assets/topics.json is malformed")
+            .topics;
+        let forms = entries
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, e)| e.forms.iter().map(move |f| (normalize(f), idx)))
+            .collect();
+        Self {
+            entries,
+            topics,
+            forms
+        }
+    }
+
+    /// All entries in bundled order.
+    #[must_use]
+    pub fn entries(&self) -> &[DictionaryEntry] {
+        &self.entries
+    }
+
+    /// All topics.
+    #[must_use]
+    pub fn topics(&self) -> &[Topic] {
+        &self.topics
+    }
+
+    /// Finds an entry by id.
+    #[must_use]
+    pub fn get(&self, id: &str) -> Option<&DictionaryEntry> {
+        self.entries.iter().find(|e| e.id == id)
+    }
+
+    /// Finds the entry a word form belongs to.
+    #[must_use]
+    pub fn lookup(&self, word: &str) -> Option<&DictionaryEntry> {
+        self.forms
+            .get(&normalize(word))
+            .and_then(|idx| self.entries.get(*idx))
+    }
+
+    /// Entries whose name or summary contains the query.
+    #[must_use]
+    pub fn search(&self, query: &str, kind: Option<EntryKind>) -> Vec<&DictionaryEntry> {
+        let query = normalize(query.trim());
+        self.entries
+            .iter()
+            .filter(|e| kind.is_none_or(|k| e.kind == k))
+            .filter(|e| {
+                query.is_empty()
+                    || normalize(&e.name).contains(&query)
+                    || normalize(&e.summary).contains(&query)
+            })
+            .collect()
+    }
+}
+
+/// Returns the bundled dictionary, parsed on first use.
+///
+/// # Panics
+///
+/// Panics if the bundled `dictionary.json` or `topics.json` is malformed.
+#[must_use]
+pub fn dictionary() -> &'static Dictionary {
+    static DICTIONARY: OnceLock<Dictionary> = OnceLock::new();
+    DICTIONARY.get_or_init(Dictionary::bundled)
+}
+
+/// Resolves references to verses of the cached Bible.
+///
+/// References not found in the cache are returned with no verses.
+#[must_use]
+pub fn resolve(bible: &BibleCache, references: &[Reference]) -> Vec<(Reference, Vec<Verse>)> {
+    references
+        .iter()
+        .map(|r| (*r, bible.get_passage(r)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_references_parse() {
+        let dictionary = dictionary();
+        let entries = dictionary
+            .entries()
+            .iter()
+            .map(|e| (&e.name, &e.references));
+        let topics = dictionary.topics().iter().map(|t| (&t.name, &t.references));
+        for (name, references) in entries.chain(topics) {
+            for reference in references {
+                assert!(Reference::parse(reference).is_some(), "{name}: {reference}");
+            }
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! Harmony of the Gospels - events of the life of Christ with the passage
+//! in each Gospel that tells them.
+
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::reference::Reference;
+
+/// Harmony shipped with the app.
+const BUNDLED_HARMONY: &str = include_str!("../assets/harmony.json");
+
+/// One of the four Gospels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gospel {
+    /// Gospel of Matthew.
+    Matthew,
+    /// Gospel of Mark.
+    Mark,
+    /// Gospel of Luke.
+    Luke,
+    /// Gospel of John.
+    John
+}
+
+impl Gospel {
+    /// All Gospels in canonical order.
+    pub const ALL: [Self; 4] = [Self::Matthew, Self::Mark, Self::Luke, Self::John];
+
+    /// Book ID of the Gospel.
+    #[must_use]
+    pub const fn book_id(self) -> i16 {
+        match self {
+            Self::Matthew => 40,
+            Self::Mark => 41,
+            Self::Luke => 42,
+            Self::John => 43
+        }
+    }
+
+    /// Gospel of a book, if the book is one.
+    #[must_use]
+    pub fn from_book(book_id: i16) -> Option<Self> {
+        Self::ALL.into_iter().find(|g| g.book_id() == book_id)
+    }
+
+    /// Column header.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Matthew => "Матфей",
+            Self::Mark => "Марк",
+            Self::Luke => "Лука",
+            Self::John => "Иоанн"
+        }
+    }
+}
+
+/// Gospel event with its accounts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HarmonyEvent {
+    /// Event title.
+    pub title:   String,
+    /// Passage in Matthew.
+    #[serde(default)]
+    pub matthew: Option<String>,
+    /// Passage in Mark.
+    #[serde(default)]
+    pub mark:    Option<String>,
+    /// Passage in Luke.
+    #[serde(default)]
+    pub luke:    Option<String>,
+    /// Passage in John.
+    #[serde(default)]
+    pub john:    Option<String>
+}
+
+impl HarmonyEvent {
+    /// Passage telling the event in a Gospel.
+    #[must_use]
+    pub fn passage(&self, gospel: Gospel) -> Option<Reference> {
+        let reference = match gospel {
+            Gospel::Matthew => &self.matthew,
+            Gospel::Mark => &self.mark,
+            Gospel::Luke => &self.luke,
+            Gospel::John => &self.john
+        };
+        reference.as_deref().and_then(Reference::parse)
+    }
+
+    /// Accounts in canonical order, skipping Gospels that omit the event.
+    #[must_use]
+    pub fn accounts(&self) -> Vec<(Gospel, Reference)> {
+        Gospel::ALL
+            .into_iter()
+            .filter_map(|g| self.passage(g).map(|r| (g, r)))
+            .collect()
+    }
+
+    /// Checks whether the event is told in a chapter.
+    #[must_use]
+    pub fn touches(&self, book_id: i16, chapter: i16) -> bool {
+        Gospel::from_book(book_id)
+            .and_then(|g| self.passage(g))
+            .is_some_and(|r| r.chapter <= chapter && chapter <= r.end_chapter)
+    }
+
+    /// Checks whether the account of the event includes a verse.
+    #[must_use]
+    pub fn contains(&self, book_id: i16, chapter: i16, verse: i16) -> bool {
+        Gospel::from_book(book_id)
+            .and_then(|g| self.passage(g))
+            .is_some_and(|r| r.contains(chapter, verse))
+    }
+}
+
+/// Events of one period.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HarmonySection {
+    /// Period title.
+    pub title:  String,
+    /// Events in chronological order.
+    pub events: Vec<HarmonyEvent>
+}
+
+/// The whole harmony.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Harmony {
+    /// Revision of the table.
+    pub version:  u32,
+    /// Periods in chronological order.
+    pub sections: Vec<HarmonySection>
+}
+
+impl Harmony {
+    /// Parses the harmony shipped with the app.
+    fn bundled() -> Self {
+        serde_json::from_str(BUNDLED_HARMONY).expect("assets/harmony.json is malformed")
+    }
+
+    /// All events in chronological order.
+    pub fn events(&self) -> impl Iterator<Item = &HarmonyEvent> {
+        self.sections.iter().flat_map(|s| &s.events)
+    }
+
+    /// Events told in a chapter, in the order of that Gospel.
+    #[must_use]
+    pub fn events_in(&self, book_id: i16, chapter: i16) -> Vec<&HarmonyEvent> {
+        let mut events: Vec<_> = self
+            .events()
+            .filter(|e| e.touches(book_id, chapter))
+            .collect();
+        let gospel = Gospel::from_book(book_id);
+        events.sort_by_key(|e| {
+            gospel
+                .and_then(|g| e.passage(g))
+                .map(|r| (r.chapter, r.verse.unwrap_or(1)))
+        });
+        events
+    }
+}
+
+/// Returns the bundled harmony, parsed on first use.
+///
+/// # Panics
+///
+/// Panics if the bundled `harmony.json` is malformed.
+#[must_use]
+pub fn harmony() -> &'static Harmony {
+    static HARMONY: OnceLock<Harmony> = OnceLock::new();
+    HARMONY.get_or_init(Harmony::bundled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_references_parse() {
+        for event in harmony().events() {
+            let accounts = [&event.matthew, &event.mark, &event.luke, &event.john];
+            for reference in accounts.into_iter().flatten() {
+                assert!(
+                    Reference::parse(reference).is_some(),
+                    "{}: {reference}",
+                    event.title
+                );
+            }
+        }
+    }
+}
@@ -2,11 +2,30 @@
 
 pub mod api;
 pub mod app;
+pub mod audio;
 pub mod bible;
+pub mod book_intro;
+pub mod calendar;
+pub mod commentary;
 pub mod components;
+pub mod daily;
+pub mod dictionary;
 pub mod haptic;
+pub mod harmony;
+pub mod maps;
+pub mod memorize;
 pub mod pages;
+pub mod plans;
+pub mod presentation;
+pub mod query;
+pub mod reference;
+pub mod search;
+pub mod share;
+pub mod speech;
 pub mod state;
+pub mod stats;
+pub mod verse_image;
+pub mod versification;
 
 pub use app::App;
 pub use bible::{BibleCache, BibleProvider};
@@ -0,0 +1,371 @@
+//! Offline maps of biblical lands.
+//!
+//! Geography, places and routes ship with the app as `GeoJSON`; each map is a
+//! bounding box over the shared features plus the features tagged with its
+//! id. Maps are drawn as SVG in an equirectangular projection.
+
+use std::{collections::HashMap, fmt::Write, sync::OnceLock};
+
+use serde::Deserialize;
+
+use crate::{
+    reference::{Reference, parse_references},
+    search::{normalize, words}
+};
+
+/// Maps shipped with the app.
+const BUNDLED_MAPS: &str = include_str!("../assets/maps.geojson");
+
+/// Width of the SVG view box; the height follows the map bounds.
+pub const VIEW_WIDTH: f64 = 1000.0;
+
+/// Longitude and latitude in degrees.
+pub type Position = [f64; 2];
+
+/// `GeoJSON` geometry subset used by the maps.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", content = "coordinates")]
+pub enum Geometry {
+    /// Single position.
+    Point(Position),
+    /// Open line.
+    LineString(Vec<Position>),
+    /// Outer ring followed by holes.
+    Polygon(Vec<Vec<Position>>)
+}
+
+/// What a feature depicts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FeatureKind {
+    /// Sea or gulf.
+    Sea,
+    /// Island drawn over a sea.
+    Island,
+    /// Lake.
+    Lake,
+    /// River.
+    River,
+    /// Town or landmark.
+    Place,
+    /// Journey route.
+    Route,
+    /// Territory label.
+    Region,
+    /// Tribal allotment or other filled territory.
+    Territory
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct Properties {
+    kind:       FeatureKind,
+    name:       String,
+    #[serde(default)]
+    id:         Option<String>,
+    #[serde(default)]
+    maps:       Vec<String>,
+    #[serde(default)]
+    references: Vec<String>,
+    #[serde(default)]
+    forms:      Vec<String>,
+    #[serde(default)]
+    entry:      Option<String>
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct Feature {
+    geometry:   Geometry,
+    properties: Properties
+}
+
+#[derive(Debug, Deserialize)]
+struct FeatureCollection {
+    maps:     Vec<BibleMap>,
+    features: Vec<Feature>
+}
+
+/// Map of one region or story.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct BibleMap {
+    /// Identifier used in links.
+    pub id:          String,
+    /// Map title.
+    pub title:       String,
+    /// One-line description.
+    pub description: String,
+    /// West, south, east and north edges in degrees.
+    pub bounds:      [f64; 4]
+}
+
+/// Geography drawn under places and routes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shape {
+    /// Sea, island, lake, river or territory label.
+    pub kind:     FeatureKind,
+    /// Name shown on hover.
+    pub name:     String,
+    /// Shape geometry.
+    pub geometry: Geometry,
+    /// Maps the shape belongs to, empty for every map.
+    maps:         Vec<String>
+}
+
+/// Town or landmark with the passages that mention it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Place {
+    /// Identifier used in links.
+    pub id:         String,
+    /// Place name.
+    pub name:       String,
+    /// Location.
+    pub position:   Position,
+    /// Maps showing the place.
+    pub maps:       Vec<String>,
+    /// Passages about the place.
+    pub references: Vec<String>,
+    /// Dictionary entry id, if the dictionary has one.
+    pub entry:      Option<String>,
+    /// Word forms recognized in the Bible text.
+    forms:          Vec<String>
+}
+
+/// Journey drawn as a line through places.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route {
+    /// Route name.
+    pub name:       String,
+    /// Points of the line.
+    pub points:     Vec<Position>,
+    /// Passages narrating the journey.
+    pub references: Vec<String>,
+    /// Maps showing the route.
+    maps:           Vec<String>
+}
+
+/// All bundled maps with their features.
+#[derive(Debug, Default)]
+pub struct Atlas {
+    maps:   Vec<BibleMap>,
+    shapes: Vec<Shape>,
+    places: Vec<Place>,
+    routes: Vec<Route>,
+    /// Normalized word form → place index.
+    forms:  HashMap<String, usize>
+}
+
+impl Atlas {
+    /// Parses the bundled `GeoJSON`.
+    fn bundled() -> Self {
+        let collection = serde_json::from_str::<FeatureCollection>(BUNDLED_MAPS)
+            .expect("assets/maps.geojson is malformed");
+        let mut atlas = Self {
+            maps: collection.maps,
+            ..Self::default()
+        };
+        for Feature {
+            geometry,
+            properties: p
+        } in collection.features
+        {
+            match (p.kind, geometry) {
+                (FeatureKind::Place, Geometry::Point(position)) => atlas.places.push(Place {
+                    id: p.id.unwrap_or_else(|| p.name.clone()),
+                    name: p.name,
+                    position,
+                    maps: p.maps,
+                    references: p.references,
+                    entry: p.entry,
+                    forms: p.forms
+                }),
+                (FeatureKind::Route, Geometry::LineString(points)) => atlas.routes.push(Route {
+                    name: p.name,
+                    points,
+                    references: p.references,
+                    maps: p.maps
+                }),
+                (kind, geometry) => atlas.shapes.push(Shape {
+                    kind,
+                    name: p.name,
+                    geometry,
+                    maps: p.maps
+                })
+            }
+        }
+        atlas.forms = atlas
+            .places
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, p)| p.forms.iter().map(move |f| (normalize(f), idx)))
+            .collect();
+        atlas
+    }
+
+    /// All maps.
+    #[must_use]
+    pub fn maps(&self) -> &[BibleMap] {
+        &self.maps
+    }
+
+    /// Finds a map by id.
+    #[must_use]
+    pub fn get(&self, id: &str) -> Option<&BibleMap> {
+        self.maps.iter().find(|m| m.id == id)
+    }
+
+    /// Geography of a map in file order, so islands and lakes draw over seas.
+    pub fn shapes<'a>(&'a self, map: &'a str) -> impl Iterator<Item = &'a Shape> {
+        self.shapes
+            .iter()
+            .filter(move |s| s.maps.is_empty() || s.maps.iter().any(|m| m == map))
+    }
+
+    /// Places shown on a map.
+    pub fn places<'a>(&'a self, map: &'a str) -> impl Iterator<Item = &'a Place> {
+        self.places
+            .iter()
+            .filter(move |p| p.maps.iter().any(|m| m == map))
+    }
+
+    /// Routes shown on a map.
+    pub fn routes<'a>(&'a self, map: &'a str) -> impl Iterator<Item = &'a Route> {
+        self.routes
+            .iter()
+            .filter(move |r| r.maps.iter().any(|m| m == map))
+    }
+
+    /// Finds a place by id.
+    #[must_use]
+    pub fn place(&self, id: &str) -> Option<&Place> {
+        self.places.iter().find(|p| p.id == id)
+    }
+
+    /// First known place named in a text.
+    #[must_use]
+    pub fn place_in(&self, text: &str) -> Option<&Place> {
+        words(text).find_map(|w| {
+            self.forms
+                .get(&normalize(w))
+                .and_then(|idx| self.places.get(*idx))
+        })
+    }
+}
+
+impl Place {
+    /// Passages that parse as references.
+    #[must_use]
+    pub fn references(&self) -> Vec<Reference> {
+        parse_references(&self.references)
+    }
+
+    /// Link to the place on its first map.
+    #[must_use]
+    pub fn href(&self) -> Option<String> {
+        let map = self.maps.first()?;
+        Some(format!("/maps/{map}?place={}", self.id))
+    }
+}
+
+impl Route {
+    /// Passages that parse as references.
+    #[must_use]
+    pub fn references(&self) -> Vec<Reference> {
+        parse_references(&self.references)
+    }
+}
+
+/// Returns the bundled maps, parsed on first use.
+///
+/// # Panics
+///
+/// Panics if the bundled `maps.geojson` is malformed.
+#[must_use]
+pub fn atlas() -> &'static Atlas {
+    static ATLAS: OnceLock<Atlas> = OnceLock::new();
+    ATLAS.get_or_init(Atlas::bundled)
+}
+
+/// Equirectangular projection of map bounds onto the SVG view box.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Projection {
+    west:     f64,
+    north:    f64,
+    /// View box units per degree of latitude.
+    scale:    f64,
+    /// Shrinks longitude at the middle latitude of the map.
+    x_factor: f64,
+    height:   f64
+}
+
+impl Projection {
+    /// Projection fitting the bounds into [`VIEW_WIDTH`].
+    #[must_use]
+    pub fn new(bounds: [f64; 4]) -> Self {
+        let [west, south, east, north] = bounds;
+        let x_factor = f64::midpoint(south, north).to_radians().cos();
+        let scale = VIEW_WIDTH / ((east - west) * x_factor);
+        Self {
+            west,
+            north,
+            scale,
+            x_factor,
+            height: (north - south) * scale
+        }
+    }
+
+    /// SVG `viewBox` attribute.
+    #[must_use]
+    pub fn view_box(&self) -> String {
+        format!("0 0 {VIEW_WIDTH} {:.0}", self.height)
+    }
+
+    /// Projects a position to view box coordinates.
+    #[must_use]
+    pub fn point(&self, [lon, lat]: Position) -> (f64, f64) {
+        (
+            (lon - self.west) * self.x_factor * self.scale,
+            (self.north - lat) * self.scale
+        )
+    }
+
+    /// SVG path data through positions, closed for polygon rings.
+    #[must_use]
+    pub fn path(&self, points: &[Position], closed: bool) -> String {
+        let mut d = String::new();
+        for (idx, position) in points.iter().enumerate() {
+            let (x, y) = self.point(*position);
+            let command = if idx == 0 { 'M' } else { 'L' };
+            let _ = write!(d, "{command}{x:.1},{y:.1}");
+        }
+        if closed {
+            d.push('Z');
+        }
+        d
+    }
+
+    /// SVG path data of a geometry; points have no path.
+    #[must_use]
+    pub fn geometry(&self, geometry: &Geometry) -> String {
+        match geometry {
+            Geometry::Point(_) => String::new(),
+            Geometry::LineString(points) => self.path(points, false),
+            Geometry::Polygon(rings) => rings.iter().map(|r| self.path(r, true)).collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_references_parse() {
+        let atlas = atlas();
+        let places = atlas.places.iter().map(|p| (&p.name, &p.references));
+        let routes = atlas.routes.iter().map(|r| (&r.name, &r.references));
+        for (name, references) in places.chain(routes) {
+            for reference in references {
+                assert!(Reference::parse(reference).is_some(), "{name}: {reference}");
+            }
+        }
+    }
+}
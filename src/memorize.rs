@@ -0,0 +1,303 @@
+//! Scripture memorization - practice helpers and SM-2 review scheduling.
+
+use chrono::{Days, NaiveDate};
+use gloo_storage::{LocalStorage, Storage};
+use revelation_bible::Verse;
+use serde::{Deserialize, Serialize};
+
+use crate::share::{citation, passage_text};
+
+const DECK_KEY: &str = "memory_verses";
+
+/// Lowest ease factor allowed by SM-2, in hundredths.
+const MIN_EASE: u32 = 130;
+/// Ease factor of a new card, in hundredths.
+const START_EASE: u32 = 250;
+
+/// Verse being memorized with its review schedule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryCard {
+    /// Reference such as `Ин 3:16-17`, unique within the deck.
+    pub reference:   String,
+    /// Passage text.
+    pub text:        String,
+    /// Book of the first verse.
+    pub book_id:     i16,
+    /// Chapter of the first verse.
+    pub chapter:     i16,
+    /// First verse.
+    pub verse:       i16,
+    /// SM-2 ease factor in hundredths (250 = 2.5).
+    pub ease:        u32,
+    /// Current interval in days.
+    pub interval:    u32,
+    /// Successful reviews in a row.
+    pub repetitions: u32,
+    /// Next review date.
+    pub due:         NaiveDate
+}
+
+impl MemoryCard {
+    /// Creates a card from verses, due immediately.
+    ///
+    /// Returns `None` if `verses` is empty.
+    #[must_use]
+    pub fn from_verses(verses: &[Verse], today: NaiveDate) -> Option<Self> {
+        let first = verses.first()?;
+        Some(Self {
+            reference:   citation(verses)?,
+            text:        passage_text(verses),
+            book_id:     first.book_id,
+            chapter:     first.chapter,
+            verse:       first.verse,
+            ease:        START_EASE,
+            interval:    0,
+            repetitions: 0,
+            due:         today
+        })
+    }
+
+    /// Checks whether the card should be reviewed on `today`.
+    #[must_use]
+    pub fn is_due(&self, today: NaiveDate) -> bool {
+        self.due <= today
+    }
+
+    /// Applies an SM-2 review with quality 0-5.
+    pub fn review(&mut self, quality: u8, today: NaiveDate) {
+        let quality = quality.min(5);
+        if quality >= 3 {
+            self.interval = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => (self.interval * self.ease + 50) / 100
+            };
+            self.repetitions += 1;
+        } else {
+            self.repetitions = 0;
+            self.interval = 1;
+        }
+
+        // EF' = EF + 0.1 - (5 - q) * (0.08 + (5 - q) * 0.02)
+        let miss = u32::from(5 - quality);
+        self.ease = (self.ease + 10)
+            .saturating_sub(miss * (8 + miss * 2))
+            .max(MIN_EASE);
+        self.due = today
+            .checked_add_days(Days::new(u64::from(self.interval)))
+            .unwrap_or(today);
+    }
+}
+
+/// Practice mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PracticeMode {
+    /// First letter of every word.
+    #[default]
+    FirstLetters,
+    /// Some words hidden.
+    Blanks,
+    /// Type the whole passage.
+    Recall
+}
+
+impl PracticeMode {
+    /// All modes in display order.
+    pub const ALL: [Self; 3] = [Self::FirstLetters, Self::Blanks, Self::Recall];
+
+    /// Display label.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::FirstLetters => "Первые буквы",
+            Self::Blanks => "Пропуски",
+            Self::Recall => "Наизусть"
+        }
+    }
+}
+
+/// Word of a practice text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    /// Word as written, with punctuation.
+    pub word:   String,
+    /// Whether the word is hidden from the user.
+    pub hidden: bool
+}
+
+/// Replaces every word with its first letter, keeping punctuation.
+#[must_use]
+pub fn first_letters(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| {
+            let Some(start) = word.find(char::is_alphanumeric) else {
+                return word.to_string();
+            };
+            let letter_end = start + word[start..].chars().next().map_or(0, char::len_utf8);
+            let tail = word.rfind(char::is_alphanumeric).map_or(word.len(), |i| {
+                i + word[i..].chars().next().map_or(0, char::len_utf8)
+            });
+            format!("{}{}", &word[..letter_end], &word[tail..])
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Hides words for the blanks mode; more words are hidden as the card
+/// matures.
+#[must_use]
+pub fn blanks(text: &str, repetitions: u32) -> Vec<Token> {
+    // Every 4th word at first, then every 3rd, then every other one
+    let step = match repetitions {
+        0 => 4,
+        1 | 2 => 3,
+        _ => 2
+    };
+    text.split_whitespace()
+        .enumerate()
+        .map(|(idx, word)| Token {
+            word:   word.to_string(),
+            hidden: idx % step == step - 1
+        })
+        .collect()
+}
+
+/// Word of a checked recall attempt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckedWord {
+    /// Expected word.
+    pub word:    String,
+    /// Whether the user typed it.
+    pub correct: bool
+}
+
+/// Normalizes a word for comparison: case, `ё` and punctuation are ignored.
+fn normalize_word(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .map(|c| if c == 'ё' { 'е' } else { c })
+        .collect()
+}
+
+/// Checks whether a typed word matches, forgiving one typo in longer words.
+fn same_word(expected: &str, typed: &str) -> bool {
+    if expected == typed {
+        return true;
+    }
+    let a: Vec<char> = expected.chars().collect();
+    let b: Vec<char> = typed.chars().collect();
+    if a.len() < 5 || a.len().abs_diff(b.len()) > 1 {
+        return false;
+    }
+    // Single-row Levenshtein distance
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diag = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let next = (diag + usize::from(ca != cb))
+                .min(row[j] + 1)
+                .min(row[j + 1] + 1);
+            diag = row[j + 1];
+            row[j + 1] = next;
+        }
+    }
+    row[b.len()] <= 1
+}
+
+/// Compares a typed passage with the original word by word.
+///
+/// Matching uses the longest common subsequence, so a missing or extra
+/// word does not fail the rest of the text. Case, punctuation, `ё` and a
+/// single typo per word are tolerated.
+#[must_use]
+pub fn check_recall(expected: &str, typed: &str) -> Vec<CheckedWord> {
+    let words: Vec<&str> = expected.split_whitespace().collect();
+    let wanted: Vec<String> = words.iter().map(|w| normalize_word(w)).collect();
+    let given: Vec<String> = typed
+        .split_whitespace()
+        .map(normalize_word)
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    // lcs[i][j] - common words of wanted[i..] and given[j..]
+    let mut lcs = vec![vec![0_u16; given.len() + 1]; wanted.len() + 1];
+    for i in (0..wanted.len()).rev() {
+        for j in (0..given.len()).rev() {
+            lcs[i][j] = if same_word(&wanted[i], &given[j]) {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut result = Vec::with_capacity(words.len());
+    let (mut i, mut j) = (0, 0);
+    while i < wanted.len() {
+        if j < given.len() && same_word(&wanted[i], &given[j]) {
+            result.push(CheckedWord {
+                word:    words[i].to_string(),
+                correct: true
+            });
+            i += 1;
+            j += 1;
+        } else if j < given.len() && lcs[i][j + 1] > lcs[i + 1][j] {
+            j += 1;
+        } else {
+            result.push(CheckedWord {
+                word:    words[i].to_string(),
+                correct: wanted[i].is_empty()
+            });
+            i += 1;
+        }
+    }
+    result
+}
+
+/// Maps a recall result to SM-2 quality.
+#[must_use]
+pub fn recall_quality(checked: &[CheckedWord]) -> u8 {
+    if checked.is_empty() {
+        return 0;
+    }
+    let correct = checked.iter().filter(|w| w.correct).count();
+    match correct * 100 / checked.len() {
+        95.. => 5,
+        85..=94 => 4,
+        70..=84 => 3,
+        50..=69 => 2,
+        1..=49 => 1,
+        _ => 0
+    }
+}
+
+/// Memorization deck storage.
+pub struct MemoryDeck;
+
+impl MemoryDeck {
+    /// Loads cards from `LocalStorage`.
+    #[must_use]
+    pub fn load() -> Vec<MemoryCard> {
+        LocalStorage::get(DECK_KEY).unwrap_or_default()
+    }
+
+    /// Saves cards to `LocalStorage`.
+    pub fn save(cards: &[MemoryCard]) {
+        let _ = LocalStorage::set(DECK_KEY, cards);
+    }
+
+    /// Adds a card unless its reference is already in the deck.
+    ///
+    /// Returns `false` if the card was already there.
+    pub fn add(card: MemoryCard) -> bool {
+        let mut cards = Self::load();
+        if cards.iter().any(|c| c.reference == card.reference) {
+            return false;
+        }
+        cards.push(card);
+        Self::save(&cards);
+        true
+    }
+}
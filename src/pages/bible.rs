@@ -1,7 +1,12 @@
 //! Bible reading pages - Book-style interface with dual thumb index
 
+use std::time::Duration;
+
 use leptos::{prelude::*, reactive::computed::Memo, tachys::dom::window};
-use leptos_router::hooks::use_params_map;
+use leptos_router::{
+    components::A,
+    hooks::{use_params_map, use_query_map}
+};
 use revelation_bible::{Book, ChapterInfo, Pericope, Testament};
 use revelation_ui::use_theme;
 use wasm_bindgen::{closure::Closure, prelude::*};
@@ -11,7 +16,24 @@ fn request_animation_frame(f: impl FnOnce() + 'static) {
     let _ = window().request_animation_frame(closure.unchecked_ref());
 }
 
-use crate::{api, components::BottomNav};
+use crate::{
+    api,
+    audio::ListenSource,
+    bible::{ChapterStep, adjacent_chapter, book_abbreviation, get_book_category_var},
+    components::{
+        AudioBar, BottomNav, CommentaryPanel, FindBar, FindState, HarmonySheet, HighlightedText,
+        ListenBar, ParallelChapter, ParallelPicker, ReferenceJump, SelectionBar, SymphonySheet
+    },
+    daily, haptic,
+    harmony::harmony,
+    reference::Reference,
+    speech,
+    state::AppState,
+    stats::ReadingHistory
+};
+
+/// How often reading time is saved while the reader is visible.
+const READING_TICK_SECS: u32 = 30;
 
 #[allow(dead_code)]
 mod styles {
@@ -24,6 +46,41 @@ mod styles {
 }
 use styles::{books, chapters, colors, header, reader};
 
+// `caretPositionFromPoint` is missing in older WebKit and Chromium, which only
+// have `caretRangeFromPoint`
+#[wasm_bindgen(inline_js = "
+export function caretAt(x, y) {
+    if (typeof document.caretPositionFromPoint === 'function') {
+        const position = document.caretPositionFromPoint(x, y);
+        return position && { node: position.offsetNode, offset: position.offset };
+    }
+    if (typeof document.caretRangeFromPoint === 'function') {
+        const range = document.caretRangeFromPoint(x, y);
+        return range && { node: range.startContainer, offset: range.startOffset };
+    }
+    return null;
+}
+")]
+extern "C" {
+    /// Text node and offset under a screen point.
+    type Caret;
+    fn caretAt(x: f64, y: f64) -> Option<Caret>;
+    #[wasm_bindgen(method, getter)]
+    fn node(this: &Caret) -> web_sys::Node;
+    #[wasm_bindgen(method, getter)]
+    fn offset(this: &Caret) -> u32;
+}
+
+/// Hold time before a press on a word opens its symphony.
+const LONG_PRESS: Duration = Duration::from_millis(500);
+
+/// Horizontal distance (px) after which a touch is treated as a swipe.
+const SWIPE_LOCK_PX: f64 = 12.0;
+/// Horizontal distance (px) a swipe must travel to switch chapters.
+const SWIPE_COMMIT_PX: f64 = 80.0;
+/// Drag factor applied when there is no chapter to swipe to.
+const SWIPE_RESISTANCE: f64 = 0.3;
+
 /// Bible reader with book-style navigation.
 #[must_use]
 #[component]
@@ -41,7 +98,9 @@ pub fn Bible() -> impl IntoView {
 #[must_use]
 #[component]
 pub fn BibleChapter() -> impl IntoView {
+    let app_state = expect_context::<AppState>();
     let params = use_params_map();
+    let query = use_query_map();
 
     let book_id = Memo::new(move |_| {
         params
@@ -59,6 +118,22 @@ pub fn BibleChapter() -> impl IntoView {
             .unwrap_or(1)
     });
 
+    // Follow in-app links to other chapters while this route stays mounted
+    Effect::new(move |_| {
+        app_state.current_book.set(book_id.get());
+        app_state.current_chapter.set(chapter.get());
+    });
+
+    Effect::new(move |_| {
+        let verse = query
+            .read()
+            .get("verse")
+            .and_then(|s| s.parse::<i16>().ok());
+        if verse.is_some() {
+            app_state.focus_verse.set(verse);
+        }
+    });
+
     view! {
         <BibleReader initial_book=book_id.get_untracked() initial_chapter=chapter.get_untracked()/>
     }
@@ -77,9 +152,24 @@ fn BibleReader(initial_book: i16, initial_chapter: i16) -> impl IntoView {
     app_state.current_book.set(initial_book);
     app_state.current_chapter.set(initial_chapter);
 
-    let (books_open, set_books_open) = signal(false);
+    let books_open = app_state.books_open;
+    let focus_verse = app_state.focus_verse;
     let (chapters_open, set_chapters_open) = signal(false);
     let (scroll_progress, set_scroll_progress) = signal::<Option<f64>>(None);
+    let selected = RwSignal::new(Vec::<i16>::new());
+    let listening = RwSignal::new(false);
+    let parallel_open = RwSignal::new(false);
+    let commentary_open = RwSignal::new(false);
+    let harmony_open = RwSignal::new(false);
+    let jump_open = RwSignal::new(false);
+    // Gospel chapters with events in the harmony
+    let has_harmony = Memo::new(move |_| {
+        !harmony()
+            .events_in(current_book.get(), current_chapter.get())
+            .is_empty()
+    });
+    let parallel = app_state.parallel;
+    let spoken = RwSignal::new(None::<i16>);
     let content_ref: NodeRef<leptos::html::Main> = NodeRef::new();
 
     let all_books = LocalResource::new(|| async { api::get_books_cached().await.ok() });
@@ -109,6 +199,7 @@ fn BibleReader(initial_book: i16, initial_chapter: i16) -> impl IntoView {
         // Always reset when navigation changes
         if prev.is_none() || prev != Some(current) {
             set_scroll_progress.set(None);
+            selected.set(Vec::new());
         }
 
         current
@@ -141,6 +232,88 @@ fn BibleReader(initial_book: i16, initial_chapter: i16) -> impl IntoView {
         }
     });
 
+    // Scroll to the focused verse once the chapter renders, then fade it out
+    Effect::new(move |_| {
+        let Some(verse) = focus_verse.get() else {
+            return;
+        };
+        if verses.get().flatten().is_none() {
+            return;
+        }
+
+        request_animation_frame(move || {
+            request_animation_frame(move || {
+                if let Some(el) = document().get_element_by_id(&format!("v{verse}")) {
+                    el.scroll_into_view();
+                }
+            });
+        });
+        set_timeout(
+            move || {
+                if focus_verse.get_untracked() == Some(verse) {
+                    focus_verse.set(None);
+                }
+            },
+            Duration::from_secs(3)
+        );
+    });
+
+    // Keep the verse being read in view
+    Effect::new(move |_| {
+        if let Some(verse) = spoken.get()
+            && let Some(el) = document().get_element_by_id(&format!("v{verse}"))
+        {
+            let options = web_sys::ScrollIntoViewOptions::new();
+            options.set_behavior(web_sys::ScrollBehavior::Smooth);
+            options.set_block(web_sys::ScrollLogicalPosition::Center);
+            el.scroll_into_view_with_scroll_into_view_options(&options);
+        }
+    });
+
+    // Count reading time while the page is visible
+    if let Ok(handle) = set_interval_with_handle(
+        move || {
+            if !document().hidden() {
+                ReadingHistory::update(|h| h.add_time(daily::today(), READING_TICK_SECS));
+            }
+        },
+        Duration::from_secs(u64::from(READING_TICK_SECS))
+    ) {
+        on_cleanup(move || handle.clear());
+    }
+
+    let chapter_verses = Signal::derive(move || verses.get().flatten().unwrap_or_default());
+
+    let find_open = app_state.find_open;
+    let find = FindState::new(chapter_verses);
+    provide_context(find);
+
+    // Start from the first match in a new chapter
+    Effect::new(move |_| {
+        current_book.track();
+        current_chapter.track();
+        find.current.set(0);
+    });
+
+    let selection = Signal::derive(move || {
+        let chosen = selected.get();
+        chapter_verses
+            .get()
+            .into_iter()
+            .filter(|v| chosen.contains(&v.verse))
+            .collect::<Vec<_>>()
+    });
+
+    let toggle_verse = move |num: i16| {
+        haptic::tap();
+        selected.update(|chosen| match chosen.binary_search(&num) {
+            Ok(idx) => {
+                chosen.remove(idx);
+            }
+            Err(idx) => chosen.insert(idx, num)
+        });
+    };
+
     let current_book_info = move || {
         all_books
             .get()
@@ -153,6 +326,141 @@ fn BibleReader(initial_book: i16, initial_chapter: i16) -> impl IntoView {
         current_chapter.set(1);
     };
 
+    let adjacent = move |step: ChapterStep| {
+        all_books.get_untracked().flatten().and_then(|books| {
+            adjacent_chapter(
+                &books,
+                current_book.get_untracked(),
+                current_chapter.get_untracked(),
+                step
+            )
+        })
+    };
+
+    // Listen mode continues into the next chapter like ChapterNav does
+    let next_chapter = move || {
+        let Some((book, chapter)) = adjacent(ChapterStep::Next) else {
+            return false;
+        };
+        current_book.set(book);
+        current_chapter.set(chapter);
+        true
+    };
+
+    // Swipe gesture: touch origin, axis lock and current horizontal offset
+    let swipe_origin = StoredValue::new(None::<(f64, f64)>);
+    let swipe_locked = StoredValue::new(false);
+    // Set when the last touch was a swipe, so it doesn't select a verse
+    let swiped = StoredValue::new(false);
+
+    // Long press on a word: pending timer, origin, and whether it fired
+    let press_timer = StoredValue::new(None::<TimeoutHandle>);
+    let press_origin = StoredValue::new((0.0, 0.0));
+    let long_pressed = StoredValue::new(false);
+    let symphony_word = RwSignal::new(None::<String>);
+    // Where to come back to after jumping from the symphony
+    let return_point = RwSignal::new(None::<(i16, i16, i32)>);
+    let pending_scroll = StoredValue::new(None::<i32>);
+
+    let cancel_press = move || {
+        if let Some(handle) = press_timer.get_value() {
+            handle.clear();
+        }
+        press_timer.set_value(None);
+    };
+
+    let start_press = move |ev: web_sys::PointerEvent| {
+        cancel_press();
+        long_pressed.set_value(false);
+        let (x, y) = (ev.client_x(), ev.client_y());
+        press_origin.set_value((f64::from(x), f64::from(y)));
+        let handle = set_timeout_with_handle(
+            move || {
+                press_timer.set_value(None);
+                if let Some(word) = word_at_point(x, y) {
+                    haptic::tap();
+                    long_pressed.set_value(true);
+                    symphony_word.set(Some(word));
+                }
+            },
+            LONG_PRESS
+        );
+        press_timer.set_value(handle.ok());
+    };
+
+    let on_jump = Callback::new(move |(book, chapter, verse): (i16, i16, i16)| {
+        let scroll_top = content_ref.get_untracked().map_or(0, |el| el.scroll_top());
+        return_point.set(Some((
+            current_book.get_untracked(),
+            current_chapter.get_untracked(),
+            scroll_top
+        )));
+        symphony_word.set(None);
+        harmony_open.set(false);
+        current_book.set(book);
+        current_chapter.set(chapter);
+        focus_verse.set(Some(verse));
+    });
+
+    let on_reference = Callback::new(move |reference: Reference| {
+        jump_open.set(false);
+        current_book.set(reference.book_id);
+        current_chapter.set(reference.chapter);
+        focus_verse.set(reference.verse);
+    });
+
+    let go_back = move |_| {
+        let Some((book, chapter, scroll_top)) = return_point.get_untracked() else {
+            return;
+        };
+        return_point.set(None);
+        focus_verse.set(None);
+        if (book, chapter)
+            == (
+                current_book.get_untracked(),
+                current_chapter.get_untracked()
+            )
+        {
+            if let Some(el) = content_ref.get_untracked() {
+                el.set_scroll_top(scroll_top);
+            }
+            return;
+        }
+        pending_scroll.set_value(Some(scroll_top));
+        current_book.set(book);
+        current_chapter.set(chapter);
+    };
+
+    // Restore the scroll position once the chapter we came back to renders
+    Effect::new(move |_| {
+        if verses.get().flatten().is_none() {
+            return;
+        }
+        if let Some(scroll_top) = pending_scroll.get_value() {
+            pending_scroll.set_value(None);
+            request_animation_frame(move || {
+                request_animation_frame(move || {
+                    if let Some(el) = content_ref.get_untracked() {
+                        el.set_scroll_top(scroll_top);
+                    }
+                });
+            });
+        }
+    });
+
+    let on_verse_click = Callback::new(move |num: i16| {
+        if !swiped.get_value() && !long_pressed.get_value() && !has_text_selection() {
+            toggle_verse(num);
+        }
+    });
+    let (swipe_dx, set_swipe_dx) = signal::<Option<f64>>(None);
+
+    let reset_swipe = move || {
+        swipe_origin.set_value(None);
+        swipe_locked.set_value(false);
+        set_swipe_dx.set(None);
+    };
+
     view! {
         <div class=reader::reader>
             <header class=header::header>
@@ -168,7 +476,7 @@ fn BibleReader(initial_book: i16, initial_chapter: i16) -> impl IntoView {
                     <button
                         class=header::bookBtn
                         on:click=move |_| {
-                            set_books_open.update(|v| *v = !*v);
+                            books_open.update(|v| *v = !*v);
                             set_chapters_open.set(false);
                         }
                     >
@@ -179,7 +487,7 @@ fn BibleReader(initial_book: i16, initial_chapter: i16) -> impl IntoView {
                         class=header::chapterBtn
                         on:click=move |_| {
                             set_chapters_open.update(|v| *v = !*v);
-                            set_books_open.set(false);
+                            books_open.set(false);
                         }
                     >
                         {move || current_chapter.get()}
@@ -187,6 +495,82 @@ fn BibleReader(initial_book: i16, initial_chapter: i16) -> impl IntoView {
                     </button>
                 </div>
 
+                <div class=header::actions>
+                    <button
+                        class=header::btn
+                        aria-label="Перейти к месту"
+                        on:click=move |_| {
+                            haptic::tap();
+                            jump_open.set(true);
+                        }
+                    >
+                        <JumpIcon/>
+                    </button>
+                    <button
+                        class=move || if find_open.get() {
+                            format!("{} {}", header::btn, header::btnActive)
+                        } else { header::btn.to_string() }
+                        aria-label="Найти в главе"
+                        on:click=move |_| {
+                            haptic::tap();
+                            find_open.update(|v| *v = !*v);
+                        }
+                    >
+                        <FindIcon/>
+                    </button>
+                    <button
+                        class=move || if parallel_open.get() || !parallel.with(Vec::is_empty) {
+                            format!("{} {}", header::btn, header::btnActive)
+                        } else { header::btn.to_string() }
+                        aria-label="Параллельные переводы"
+                        on:click=move |_| {
+                            haptic::tap();
+                            parallel_open.update(|v| *v = !*v);
+                        }
+                    >
+                        <ColumnsIcon/>
+                    </button>
+                    <button
+                        class=move || if commentary_open.get() {
+                            format!("{} {}", header::btn, header::btnActive)
+                        } else { header::btn.to_string() }
+                        aria-label="Толкование"
+                        on:click=move |_| {
+                            haptic::tap();
+                            commentary_open.update(|v| *v = !*v);
+                        }
+                    >
+                        <CommentaryIcon/>
+                    </button>
+                    <Show when=move || has_harmony.get()>
+                        <button
+                            class=header::btn
+                            aria-label="Параллельные места"
+                            on:click=move |_| {
+                                haptic::tap();
+                                harmony_open.set(true);
+                            }
+                        >
+                            <HarmonyIcon/>
+                        </button>
+                    </Show>
+                    <button
+                        class=move || if listening.get() {
+                            format!("{} {}", header::btn, header::btnActive)
+                        } else { header::btn.to_string() }
+                        aria-label="Слушать главу"
+                        on:click=move |_| {
+                            haptic::tap();
+                            listening.update(|v| *v = !*v);
+                        }
+                    >
+                        <HeadphonesIcon/>
+                    </button>
+                </div>
+
+                <Show when=move || jump_open.get()>
+                    <ReferenceJump on_jump=on_reference on_close=move || jump_open.set(false)/>
+                </Show>
             </header>
 
             <div class=reader::content>
@@ -207,6 +591,57 @@ fn BibleReader(initial_book: i16, initial_chapter: i16) -> impl IntoView {
                 <main
                     node_ref=content_ref
                     class=reader::text
+                    on:pointerdown=move |ev| {
+                        if ev.pointer_type() != "touch"
+                            || books_open.get_untracked()
+                            || chapters_open.get_untracked()
+                        {
+                            return;
+                        }
+                        swipe_origin.set_value(Some((f64::from(ev.client_x()), f64::from(ev.client_y()))));
+                        swipe_locked.set_value(false);
+                        swiped.set_value(false);
+                    }
+                    on:pointermove=move |ev| {
+                        let Some((x0, y0)) = swipe_origin.get_value() else {
+                            return;
+                        };
+                        let dx = f64::from(ev.client_x()) - x0;
+                        let dy = f64::from(ev.client_y()) - y0;
+                        if !swipe_locked.get_value() {
+                            // Vertical scroll wins, let the browser handle it
+                            if dy.abs() > dx.abs() && dy.abs() > SWIPE_LOCK_PX {
+                                reset_swipe();
+                                return;
+                            }
+                            if dx.abs() < SWIPE_LOCK_PX {
+                                return;
+                            }
+                            swipe_locked.set_value(true);
+                        }
+                        let offset = if adjacent(swipe_step(dx)).is_some() {
+                            dx
+                        } else {
+                            dx * SWIPE_RESISTANCE
+                        };
+                        set_swipe_dx.set(Some(offset));
+                    }
+                    on:pointerup=move |_| {
+                        swiped.set_value(swipe_locked.get_value());
+                        if let Some(dx) = swipe_dx.get_untracked()
+                            && dx.abs() >= SWIPE_COMMIT_PX
+                            && let Some((book, chapter)) = adjacent(swipe_step(dx))
+                        {
+                            haptic::tap();
+                            current_book.set(book);
+                            current_chapter.set(chapter);
+                            if let Some(el) = content_ref.get_untracked() {
+                                el.set_scroll_top(0);
+                            }
+                        }
+                        reset_swipe();
+                    }
+                    on:pointercancel=move |_| reset_swipe()
                     on:scroll=move |ev| {
                         let target = ev.target().unwrap();
                         let el = target.unchecked_ref::<web_sys::HtmlElement>();
@@ -227,27 +662,78 @@ fn BibleReader(initial_book: i16, initial_chapter: i16) -> impl IntoView {
                         <Show
                             when=move || chapters_open.get()
                             fallback=move || view! {
+                                <div
+                                    class=move || if swipe_dx.get().is_some() {
+                                        format!("{} {}", reader::swipeTrack, reader::swipeDragging)
+                                    } else {
+                                        reader::swipeTrack.to_string()
+                                    }
+                                    style:transform=move || format!("translateX({}px)", swipe_dx.get().unwrap_or(0.0))
+                                    on:pointerdown=start_press
+                                    on:pointermove=move |ev| {
+                                        let (x0, y0) = press_origin.get_value();
+                                        let moved = (f64::from(ev.client_x()) - x0)
+                                            .hypot(f64::from(ev.client_y()) - y0);
+                                        if moved > SWIPE_LOCK_PX {
+                                            cancel_press();
+                                        }
+                                    }
+                                    on:pointerup=move |_| cancel_press()
+                                    on:pointercancel=move |_| cancel_press()
+                                >
+                                <Show when=move || find_open.get()>
+                                    <FindBar find=find on_close=move || find_open.set(false)/>
+                                </Show>
+                                <Show when=move || parallel_open.get()>
+                                    <ParallelPicker/>
+                                </Show>
                                 <Suspense fallback=|| view! { <VersesLoading/> }>
-                                    {move || verses.get().flatten().map(|verses| view! {
+                                    {move || verses.get().flatten().map(|verses| if parallel.with(Vec::is_empty) { view! {
                                         <div>
                                             {verses.into_iter().map(|v| {
                                                 let text = v.text.clone();
+                                                let num = v.verse;
                                                 view! {
-                                                    <span class=move || if verse_per_line.get() { reader::verseBlock } else { "" }>
+                                                    <span
+                                                        id=format!("v{num}")
+                                                        class=move || format!(
+                                                            "{} {} {} {}",
+                                                            if verse_per_line.get() { reader::verseBlock } else { "" },
+                                                            if focus_verse.get() == Some(num) { reader::verseFocus } else { "" },
+                                                            if spoken.get() == Some(num) { reader::verseSpoken } else { "" },
+                                                            if selected.with(|s| s.contains(&num)) { reader::verseSelected } else { "" }
+                                                        )
+                                                        on:click=move |_| on_verse_click.run(num)
+                                                    >
                                                         <sup class=reader::verseNum>{v.verse}</sup>
-                                                        {text}
+                                                        <HighlightedText verse=num text=text/>
                                                         " "
                                                     </span>
                                                 }
                                             }).collect::<Vec<_>>()}
                                         </div>
-                                    })}
+                                    }.into_any() } else { view! {
+                                        <ParallelChapter
+                                            verses=verses
+                                            selected=selected
+                                            focus_verse=focus_verse
+                                            spoken=spoken
+                                            on_toggle=on_verse_click
+                                        />
+                                    }.into_any() })}
                                 </Suspense>
                                 <ChapterNav
                                     current_book=current_book
                                     current_chapter=current_chapter
                                     all_books=all_books
                                 />
+                                </div>
+                                <SwipePeek
+                                    swipe_dx=swipe_dx
+                                    current_book=current_book
+                                    current_chapter=current_chapter
+                                    all_books=all_books
+                                />
                                 <div class=reader::navSpacer></div>
                             }
                         >
@@ -282,7 +768,7 @@ fn BibleReader(initial_book: i16, initial_chapter: i16) -> impl IntoView {
                                     on_select=move |book_id| {
                                         current_book.set(book_id);
                                         current_chapter.set(1);
-                                        set_books_open.set(false);
+                                        books_open.set(false);
                                     }
                                 />
                             }
@@ -304,13 +790,186 @@ fn BibleReader(initial_book: i16, initial_chapter: i16) -> impl IntoView {
                         }
                     })}
                 </Suspense>
+
+                <Show when=move || commentary_open.get()>
+                    <CommentaryPanel
+                        book=current_book
+                        chapter=current_chapter
+                        on_close=move || commentary_open.set(false)
+                    />
+                </Show>
             </div>
 
+            <div class=reader::dock>
+                {move || return_point.get().map(|(book, chapter, _)| view! {
+                    <button class=reader::returnBtn on:click=go_back>
+                        {format!("← {} {chapter}", book_abbreviation(book).unwrap_or_default())}
+                    </button>
+                })}
+                <Show when=move || !selected.with(Vec::is_empty)>
+                    <SelectionBar selection=selection on_clear=move || selected.set(Vec::new())/>
+                </Show>
+                <Show when=move || listening.get()>
+                    {move || {
+                        let on_close = move || listening.set(false);
+                        let speech = speech::is_supported();
+                        if app_state.listen_source.get() == ListenSource::Speech && speech {
+                            view! {
+                                <ListenBar
+                                    verses=chapter_verses
+                                    spoken=spoken
+                                    advance=next_chapter
+                                    on_close=on_close
+                                />
+                            }.into_any()
+                        } else {
+                            view! {
+                                <AudioBar spoken=spoken advance=next_chapter on_close=on_close/>
+                            }.into_any()
+                        }
+                    }}
+                </Show>
+            </div>
+
+            {move || symphony_word.get().map(|word| view! {
+                <SymphonySheet word=word on_close=move || symphony_word.set(None) on_jump=on_jump/>
+            })}
+
+            <Show when=move || harmony_open.get()>
+                <HarmonySheet
+                    book=current_book.get_untracked()
+                    chapter=current_chapter.get_untracked()
+                    verse=selected.with_untracked(|s| s.iter().min().copied())
+                    on_close=move || harmony_open.set(false)
+                    on_jump=on_jump
+                />
+            </Show>
+
             <BottomNav/>
         </div>
     }
 }
 
+/// Returns the word under a screen point, or the selected word.
+fn word_at_point(x: i32, y: i32) -> Option<String> {
+    caretAt(f64::from(x), f64::from(y))
+        .and_then(|caret| {
+            let text = caret.node().text_content()?;
+            // The caret offset counts UTF-16 code units
+            let offset = usize::try_from(caret.offset()).ok()?;
+            let mut units = 0;
+            let at = text
+                .char_indices()
+                .find(|(_, c)| {
+                    units += c.len_utf16();
+                    units > offset
+                })
+                .map_or(text.len(), |(idx, _)| idx);
+            let is_word = |c: char| c.is_alphanumeric() || c == '-';
+            let start = text[..at]
+                .char_indices()
+                .rev()
+                .take_while(|(_, c)| is_word(*c))
+                .last()
+                .map_or(at, |(idx, _)| idx);
+            let end = text[at..]
+                .find(|c: char| !is_word(c))
+                .map_or(text.len(), |i| at + i);
+            Some(text[start..end].trim_matches('-').to_string())
+        })
+        .or_else(|| {
+            let selection = window().get_selection().ok().flatten()?.to_string();
+            Some(String::from(selection).trim().to_string())
+        })
+        .filter(|word| !word.is_empty() && !word.contains(char::is_whitespace))
+}
+
+/// Checks whether the user is selecting text with the native selection.
+fn has_text_selection() -> bool {
+    window()
+        .get_selection()
+        .ok()
+        .flatten()
+        .is_some_and(|sel| !sel.is_collapsed())
+}
+
+/// Chapter direction for a horizontal swipe offset.
+const fn swipe_step(dx: f64) -> ChapterStep {
+    if dx < 0.0 {
+        ChapterStep::Next
+    } else {
+        ChapterStep::Prev
+    }
+}
+
+/// Preview of the adjacent chapter shown while swiping.
+#[must_use]
+#[component]
+fn SwipePeek(
+    swipe_dx: ReadSignal<Option<f64>>,
+    current_book: RwSignal<i16>,
+    current_chapter: RwSignal<i16>,
+    all_books: LocalResource<Option<Vec<Book>>>
+) -> impl IntoView {
+    let app_state = expect_context::<AppState>();
+
+    let step = Memo::new(move |_| swipe_dx.get().map(swipe_step));
+
+    // Resolved only when the direction changes, not on every pointer move
+    let target = Memo::new(move |_| {
+        let step = step.get()?;
+        let books = all_books.get().flatten()?;
+        let (book_id, chapter) =
+            adjacent_chapter(&books, current_book.get(), current_chapter.get(), step)?;
+        let name = books
+            .iter()
+            .find(|b| b.id == book_id)
+            .map(|b| b.name_ru.clone())
+            .unwrap_or_default();
+        let preview = app_state.bible.with(|bible| {
+            bible
+                .as_ref()
+                .and_then(|cache| cache.get_chapter(book_id, chapter))
+                .and_then(|verses| verses.into_iter().next())
+                .map(|v| v.text)
+        });
+        Some((step, format!("{name} {chapter}"), preview))
+    });
+
+    let progress = move || {
+        swipe_dx
+            .get()
+            .map_or(0.0, |dx| (dx.abs() / SWIPE_COMMIT_PX).min(1.0))
+    };
+
+    move || {
+        target.get().map(|(step, label, preview)| {
+            let side = match step {
+                ChapterStep::Prev => reader::peekLeft,
+                ChapterStep::Next => reader::peekRight
+            };
+            let sign = match step {
+                ChapterStep::Prev => -1.0,
+                ChapterStep::Next => 1.0
+            };
+            view! {
+                <div
+                    class=move || format!(
+                        "{} {side} {}",
+                        reader::peek,
+                        if progress() >= 1.0 { reader::peekReady } else { "" }
+                    )
+                    style:opacity=move || progress().to_string()
+                    style:transform=move || format!("translate({}%, -50%)", sign * (1.0 - progress()) * 100.0)
+                >
+                    <span class=reader::peekLabel>{label}</span>
+                    {preview.map(|text| view! { <span class=reader::peekText>{text}</span> })}
+                </div>
+            }
+        })
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Side {
     Left,
@@ -364,56 +1023,41 @@ fn ChapterNav(
     current_chapter: RwSignal<i16>,
     all_books: LocalResource<Option<Vec<Book>>>
 ) -> impl IntoView {
-    let current_book_info = move || {
-        all_books
-            .get()
-            .flatten()
-            .and_then(|books| books.into_iter().find(|b| b.id == current_book.get()))
-    };
-
-    let can_go_prev = move || {
-        let ch = current_chapter.get();
-        let book = current_book.get();
-        ch > 1 || book > 1
+    let target = move |step: ChapterStep| {
+        all_books.get().flatten().and_then(|books| {
+            adjacent_chapter(&books, current_book.get(), current_chapter.get(), step)
+        })
     };
 
-    let can_go_next = move || {
-        let ch = current_chapter.get();
-        let book = current_book.get();
-        let max_ch = current_book_info().map_or(1, |b| b.chapters_count);
-        ch < max_ch || book < 66
-    };
+    let can_go_prev = move || target(ChapterStep::Prev).is_some();
+    let can_go_next = move || target(ChapterStep::Next).is_some();
 
-    let go_prev = move |_| {
-        let ch = current_chapter.get();
-        if ch > 1 {
-            current_chapter.set(ch - 1);
-        } else {
-            let book = current_book.get();
-            if book > 1 {
-                current_book.set(book - 1);
-                // Set to last chapter of previous book
-                if let Some(books) = all_books.get().flatten()
-                    && let Some(prev_book) = books.iter().find(|b| b.id == book - 1)
-                {
-                    current_chapter.set(prev_book.chapters_count);
-                }
-            }
+    let go = move |step: ChapterStep| {
+        if let Some((book, chapter)) = target(step) {
+            current_book.set(book);
+            current_chapter.set(chapter);
         }
     };
 
-    let go_next = move |_| {
-        let ch = current_chapter.get();
-        let max_ch = current_book_info().map_or(1, |b| b.chapters_count);
-        if ch < max_ch {
-            current_chapter.set(ch + 1);
+    let read = RwSignal::new(false);
+    Effect::new(move |_| {
+        let history = ReadingHistory::load();
+        read.set(history.is_read(current_book.get(), current_chapter.get()));
+    });
+
+    let toggle_read = move |_| {
+        let (book, chapter) = (
+            current_book.get_untracked(),
+            current_chapter.get_untracked()
+        );
+        let history = ReadingHistory::update(|h| h.toggle_chapter(book, chapter, daily::today()));
+        let now_read = history.is_read(book, chapter);
+        if now_read {
+            haptic::success();
         } else {
-            let book = current_book.get();
-            if book < 66 {
-                current_book.set(book + 1);
-                current_chapter.set(1);
-            }
+            haptic::tap();
         }
+        read.set(now_read);
     };
 
     view! {
@@ -421,15 +1065,26 @@ fn ChapterNav(
             <button
                 class=reader::navBtn
                 disabled=move || !can_go_prev()
-                on:click=go_prev
+                on:click=move |_| go(ChapterStep::Prev)
             >
                 <ChevronLeftIcon/>
                 "Назад"
             </button>
+            <button
+                class=move || if read.get() {
+                    format!("{} {}", reader::navBtn, reader::navBtnDone)
+                } else {
+                    reader::navBtn.to_string()
+                }
+                aria-pressed=move || read.get().to_string()
+                on:click=toggle_read
+            >
+                {move || if read.get() { "✓ Прочитано" } else { "Прочитано" }}
+            </button>
             <button
                 class=reader::navBtn
                 disabled=move || !can_go_next()
-                on:click=go_next
+                on:click=move |_| go(ChapterStep::Next)
             >
                 "Далее"
                 <ChevronRightIcon/>
@@ -481,47 +1136,15 @@ fn BooksPanel(
                 when=move || active_tab.get() == Testament::Old
                 fallback=move || view! {
                     <ul class=chapters::booksList>
-                        {new_testament.clone().into_iter().map(|book| {
-                            let book_id = book.id;
-                            let color_class = get_book_color_class(book.id);
-                            view! {
-                                <li>
-                                    <button
-                                        class=move || format!(
-                                            "{} {} {}",
-                                            chapters::bookItem,
-                                            color_class,
-                                            if current_book.get() == book_id { chapters::bookItemActive } else { "" }
-                                        )
-                                        on:click=move |_| on_select(book_id)
-                                    >
-                                        {book.name_ru}
-                                    </button>
-                                </li>
-                            }
+                        {new_testament.clone().into_iter().map(|book| view! {
+                            <BookItem book=book current_book=current_book on_select=on_select/>
                         }).collect::<Vec<_>>()}
                     </ul>
                 }
             >
                 <ul class=chapters::booksList>
-                    {old_testament.clone().into_iter().map(|book| {
-                        let book_id = book.id;
-                        let color_class = get_book_color_class(book.id);
-                        view! {
-                            <li>
-                                <button
-                                    class=move || format!(
-                                        "{} {} {}",
-                                        chapters::bookItem,
-                                        color_class,
-                                        if current_book.get() == book_id { chapters::bookItemActive } else { "" }
-                                    )
-                                    on:click=move |_| on_select(book_id)
-                                >
-                                    {book.name_ru}
-                                </button>
-                            </li>
-                        }
+                    {old_testament.clone().into_iter().map(|book| view! {
+                        <BookItem book=book current_book=current_book on_select=on_select/>
                     }).collect::<Vec<_>>()}
                 </ul>
             </Show>
@@ -529,6 +1152,42 @@ fn BooksPanel(
     }
 }
 
+/// Book in the books panel with a link to its overview.
+#[component]
+fn BookItem(
+    book: Book,
+    current_book: RwSignal<i16>,
+    on_select: impl Fn(i16) + Copy + Send + Sync + 'static
+) -> impl IntoView {
+    let books_open = expect_context::<AppState>().books_open;
+    let book_id = book.id;
+    let color_class = get_book_color_class(book.id);
+
+    view! {
+        <li class=chapters::bookRow>
+            <button
+                class=move || format!(
+                    "{} {} {}",
+                    chapters::bookItem,
+                    color_class,
+                    if current_book.get() == book_id { chapters::bookItemActive } else { "" }
+                )
+                on:click=move |_| on_select(book_id)
+            >
+                {book.name_ru}
+            </button>
+            <A
+                href=format!("/bible/{book_id}")
+                attr:class=chapters::bookInfo
+                attr:aria-label="О книге"
+                on:click=move |_| books_open.set(false)
+            >
+                "i"
+            </A>
+        </li>
+    }
+}
+
 /// Chapters list with expandable pericopes.
 #[allow(clippy::redundant_clone)] // Clones required for Fn closures in view! macro
 #[must_use]
@@ -694,22 +1353,6 @@ const fn get_book_color_class(book_id: i16) -> &'static str {
     }
 }
 
-/// Get book category CSS variable.
-const fn get_book_category_var(book_id: i16) -> &'static str {
-    match book_id {
-        6..=17 => "var(--cat-history)",
-        18..=22 => "var(--cat-wisdom)",
-        23..=27 => "var(--cat-major-prophets)",
-        28..=39 => "var(--cat-minor-prophets)",
-        40..=43 => "var(--cat-gospels)",
-        44 => "var(--cat-acts)",
-        45..=57 => "var(--cat-paul)",
-        58..=65 => "var(--cat-general)",
-        66 => "var(--cat-revelation)",
-        _ => "var(--cat-torah)"
-    }
-}
-
 // Icons
 
 #[must_use]
@@ -736,6 +1379,88 @@ fn ChevronRightIcon() -> impl IntoView {
     }
 }
 
+#[must_use]
+#[component]
+fn FindIcon() -> impl IntoView {
+    view! {
+        <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none"
+             stroke="currentColor" stroke-width="2" stroke-linecap="round"
+             stroke-linejoin="round" width="20" height="20">
+            <circle cx="11" cy="11" r="8"/>
+            <line x1="21" y1="21" x2="16.65" y2="16.65"/>
+        </svg>
+    }
+}
+
+#[must_use]
+#[component]
+fn CommentaryIcon() -> impl IntoView {
+    view! {
+        <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none"
+             stroke="currentColor" stroke-width="2" stroke-linecap="round"
+             stroke-linejoin="round" width="20" height="20">
+            <path d="M2 4h6a4 4 0 0 1 4 4v13a3 3 0 0 0-3-3H2z"/>
+            <path d="M22 4h-6a4 4 0 0 0-4 4v13a3 3 0 0 1 3-3h7z"/>
+        </svg>
+    }
+}
+
+#[must_use]
+#[component]
+fn JumpIcon() -> impl IntoView {
+    view! {
+        <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none"
+             stroke="currentColor" stroke-width="2" stroke-linecap="round"
+             stroke-linejoin="round" width="20" height="20">
+            <path d="M5 12h14"/>
+            <polyline points="13 6 19 12 13 18"/>
+            <line x1="5" y1="5" x2="5" y2="19"/>
+        </svg>
+    }
+}
+
+#[must_use]
+#[component]
+fn HarmonyIcon() -> impl IntoView {
+    view! {
+        <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none"
+             stroke="currentColor" stroke-width="2" stroke-linecap="round"
+             stroke-linejoin="round" width="20" height="20">
+            <rect x="3" y="3" width="7" height="7" rx="1"/>
+            <rect x="14" y="3" width="7" height="7" rx="1"/>
+            <rect x="3" y="14" width="7" height="7" rx="1"/>
+            <rect x="14" y="14" width="7" height="7" rx="1"/>
+        </svg>
+    }
+}
+
+#[must_use]
+#[component]
+fn ColumnsIcon() -> impl IntoView {
+    view! {
+        <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none"
+             stroke="currentColor" stroke-width="2" stroke-linecap="round"
+             stroke-linejoin="round" width="20" height="20">
+            <rect x="3" y="4" width="18" height="16" rx="2"/>
+            <path d="M12 4v16"/>
+        </svg>
+    }
+}
+
+#[must_use]
+#[component]
+fn HeadphonesIcon() -> impl IntoView {
+    view! {
+        <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none"
+             stroke="currentColor" stroke-width="2" stroke-linecap="round"
+             stroke-linejoin="round" width="20" height="20">
+            <path d="M3 18v-6a9 9 0 0 1 18 0v6"/>
+            <path d="M21 19a2 2 0 0 1-2 2h-1a2 2 0 0 1-2-2v-3a2 2 0 0 1 2-2h3z"/>
+            <path d="M3 19a2 2 0 0 0 2 2h1a2 2 0 0 0 2-2v-3a2 2 0 0 0-2-2H3z"/>
+        </svg>
+    }
+}
+
 #[must_use]
 #[component]
 fn ChevronDownIcon() -> impl IntoView {
@@ -0,0 +1,196 @@
+//! Book overview - introduction, outline and reading progress
+
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_params_map};
+use revelation_bible::Pericope;
+
+use crate::{
+    api,
+    bible::{book_name, get_book_category_var},
+    book_intro::{book_intro, outline, reading_minutes},
+    components::{BottomNav, Header, Loading},
+    state::AppState,
+    stats::ReadingHistory
+};
+
+#[allow(dead_code)]
+mod styles {
+    stylance::import_crate_style!(pub common, "src/styles/common.module.css");
+    stylance::import_crate_style!(pub book, "src/styles/book.module.css");
+}
+use styles::{book, common};
+
+/// Overview of a book with quick jumps into its chapters.
+#[must_use]
+#[component]
+pub fn BookOverview() -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let params = use_params_map();
+    let book_id = Memo::new(move |_| {
+        params
+            .read()
+            .get("book")
+            .and_then(|s| s.parse::<i16>().ok())
+            .unwrap_or(1)
+    });
+
+    let title = Signal::derive(move || book_name(book_id.get()).unwrap_or("Книга").to_string());
+
+    let chapters = Memo::new(move |_| {
+        let id = book_id.get();
+        state.bible.with(|bible| {
+            bible.as_ref().and_then(|b| {
+                b.get_books()
+                    .into_iter()
+                    .find(|book| book.id == id)
+                    .map(|book| book.chapters_count)
+            })
+        })
+    });
+
+    let minutes = Memo::new(move |_| {
+        let chapters = chapters.get()?;
+        state.bible.with(|bible| {
+            bible
+                .as_ref()
+                .map(|b| reading_minutes(b, book_id.get(), chapters))
+        })
+    });
+
+    let pericopes = LocalResource::new(move || {
+        let id = book_id.get();
+        async move { api::get_pericopes(id).await.unwrap_or_default() }
+    });
+
+    view! {
+        <div class=common::page>
+            <Header title=title back=true/>
+
+            <div class=common::container>
+                {move || book_intro(book_id.get()).map(|intro| view! {
+                    <dl class=book::facts>
+                        <div class=book::fact>
+                            <dt>"Автор"</dt>
+                            <dd>{intro.author.clone()}</dd>
+                        </div>
+                        <div class=book::fact>
+                            <dt>"Время написания"</dt>
+                            <dd>{intro.date.clone()}</dd>
+                        </div>
+                    </dl>
+                })}
+
+                <div class=book::tiles>
+                    <div class=book::tile>
+                        <span class=book::tileValue>{move || chapters.get().map(|c| c.to_string())}</span>
+                        <span class=book::tileLabel>"глав"</span>
+                    </div>
+                    <div class=book::tile>
+                        <span class=book::tileValue>{move || minutes.get().map(format_minutes)}</span>
+                        <span class=book::tileLabel>"на чтение"</span>
+                    </div>
+                </div>
+
+                {move || chapters.get().map_or_else(
+                    || view! { <Loading/> }.into_any(),
+                    |count| view! { <Progress book_id=book_id.get() chapters=count/> }.into_any()
+                )}
+
+                <Suspense fallback=|| view! { <Loading/> }>
+                    {move || pericopes.get().map(|pericopes| view! {
+                        <Outline book_id=book_id.get_untracked() pericopes=pericopes/>
+                    })}
+                </Suspense>
+            </div>
+
+            <BottomNav/>
+        </div>
+    }
+}
+
+/// Formats minutes as `45 мин` or `3 ч 20 мин`.
+fn format_minutes(minutes: usize) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{m} мин"),
+        (h, 0) => format!("{h} ч"),
+        (h, m) => format!("{h} ч {m} мин")
+    }
+}
+
+/// Read chapters, a link to continue and the chapter grid.
+#[component]
+fn Progress(book_id: i16, chapters: i16) -> impl IntoView {
+    let history = ReadingHistory::load();
+    let read = history.read_in_book(book_id);
+    let percent = read * 100 / usize::try_from(chapters.max(1)).unwrap_or(1);
+    let next = (1..=chapters)
+        .find(|c| !history.is_read(book_id, *c))
+        .unwrap_or(1);
+    let color = get_book_category_var(book_id);
+
+    view! {
+        <section class=common::section>
+            <div class=book::progressHead>
+                <span>{format!("Прочитано {read} из {chapters}")}</span>
+                <A href=format!("/bible/{book_id}/{next}") attr:class=common::btnPrimary>
+                    {if read == 0 { "Начать чтение".to_string() } else { format!("Продолжить с главы {next}") }}
+                </A>
+            </div>
+            <div class=book::progress>
+                <div class=book::progressFill style=format!("width: {percent}%; background: {color}")/>
+            </div>
+            <div class=book::chapters>
+                {(1..=chapters).map(|chapter| {
+                    let is_read = history.is_read(book_id, chapter);
+                    view! {
+                        <A
+                            href=format!("/bible/{book_id}/{chapter}")
+                            attr:class=if is_read {
+                                format!("{} {}", book::chapter, book::chapterRead)
+                            } else {
+                                book::chapter.to_string()
+                            }
+                        >
+                            {chapter}
+                        </A>
+                    }
+                }).collect_view()}
+            </div>
+        </section>
+    }
+}
+
+/// Outline of the book from its pericope headings.
+#[component]
+fn Outline(book_id: i16, pericopes: Vec<Pericope>) -> impl IntoView {
+    let groups = outline(&pericopes);
+    if groups.is_empty() {
+        return ().into_any();
+    }
+
+    view! {
+        <section class=common::section>
+            <h2 class=common::sectionTitle>"Содержание"</h2>
+            <ol class=book::outline>
+                {groups.into_iter().map(|(chapter, items)| view! {
+                    <li class=book::outlineChapter>
+                        <span class=book::outlineNum>{chapter}</span>
+                        <ul class=book::outlineItems>
+                            {items.into_iter().map(|p| view! {
+                                <li>
+                                    <A
+                                        href=format!("/bible/{book_id}/{}?verse={}", p.chapter, p.verse)
+                                        attr:class=book::outlineLink
+                                    >
+                                        {p.heading}
+                                    </A>
+                                </li>
+                            }).collect_view()}
+                        </ul>
+                    </li>
+                }).collect_view()}
+            </ol>
+        </section>
+    }
+    .into_any()
+}
@@ -1,31 +1,74 @@
 //! Daily reading page
 
+use chrono::{Datelike, Days, NaiveDate};
 use leptos::prelude::*;
+use leptos_router::components::A;
+use wasm_bindgen_futures::spawn_local;
 
 use crate::{
-    api,
-    components::{BottomNav, Header, Loading, VerseList}
+    calendar::{self, CalendarStyle, Lection},
+    components::{BottomNav, Header, Loading, VerseList},
+    daily::{self, DailySchedule},
+    reference::Reference,
+    state::AppState
 };
 
 #[allow(dead_code)]
 mod styles {
     stylance::import_crate_style!(pub common, "src/styles/common.module.css");
+    stylance::import_crate_style!(pub reading, "src/styles/daily.module.css");
 }
-use styles::common;
+use styles::{common, reading};
 
 #[must_use]
 #[component]
 pub fn DailyReading() -> impl IntoView {
-    let reading = LocalResource::new(|| async { api::get_today_reading().await.ok().flatten() });
+    let state = expect_context::<AppState>();
+    let schedule = RwSignal::new(DailySchedule::load());
+    let date = RwSignal::new(daily::today());
+
+    // The reading works offline; the server only publishes newer schedules
+    spawn_local(async move {
+        let current = schedule.get_untracked();
+        if let Ok(Some(updated)) = current.refresh().await {
+            schedule.set(updated);
+        }
+    });
+
+    let reading = move || {
+        state.bible.with(|bible| {
+            bible
+                .as_ref()
+                .map(|bible| schedule.with(|s| s.reading(bible, date.get())))
+        })
+    };
     let response = RwSignal::new(String::new());
+    let title = Signal::derive(move || {
+        let date = date.get();
+        if date == daily::today() {
+            "Чтение на сегодня".to_string()
+        } else {
+            format!("Чтение на {}", date.format("%d.%m.%Y"))
+        }
+    });
 
     view! {
         <div class=common::page>
-            <Header title="Чтение на сегодня"/>
+            <Header title=title/>
 
             <div class=common::container>
-                <Suspense fallback=|| view! { <Loading/> }>
-                    {move || reading.get().flatten().map(|reading| view! {
+                <DateBar date=date/>
+                <A href="/plans" attr:class=common::btnSecondary>"Планы чтения"</A>
+                <A href="/stats" attr:class=common::btnSecondary>"Статистика чтения"</A>
+
+                {move || match reading() {
+                    None => view! { <Loading/> }.into_any(),
+                    Some(None) => view! {
+                        <div class=common::emptyState>
+                            <p class=common::textMuted>"На сегодня чтение не найдено"</p>
+                        </div>
+                    }.into_any(),
+                    Some(Some(reading)) => view! {
                         <div class=common::card>
                             <p class=common::textMuted style="margin-bottom: var(--space-sm);">
                                 "День " {reading.day_of_year}
@@ -52,11 +95,157 @@ pub fn DailyReading() -> impl IntoView {
                         >
                             "Отправить"
                         </button>
-                    })}
-                </Suspense>
+                    }.into_any()
+                }}
+
+                <ChurchReadings date=date/>
             </div>
 
             <BottomNav/>
         </div>
     }
 }
+
+/// Day switcher with a date picker for looking ahead.
+#[component]
+fn DateBar(date: RwSignal<NaiveDate>) -> impl IntoView {
+    let shift = move |forward: bool| {
+        date.update(|d| {
+            let next = if forward {
+                d.checked_add_days(Days::new(1))
+            } else {
+                d.checked_sub_days(Days::new(1))
+            };
+            if let Some(next) = next {
+                *d = next;
+            }
+        });
+    };
+
+    view! {
+        <div class=reading::dateBar>
+            <button class=common::btnGhost aria-label="Предыдущий день" on:click=move |_| shift(false)>"‹"</button>
+            <input
+                type="date"
+                class=reading::dateInput
+                aria-label="Дата"
+                prop:value=move || date.get().format("%Y-%m-%d").to_string()
+                on:change=move |ev| {
+                    if let Ok(value) = NaiveDate::parse_from_str(&event_target_value(&ev), "%Y-%m-%d") {
+                        date.set(value);
+                    }
+                }
+            />
+            <button class=common::btnGhost aria-label="Следующий день" on:click=move |_| shift(true)>"›"</button>
+            <Show when=move || date.get() != daily::today()>
+                <button class=common::btnGhost on:click=move |_| date.set(daily::today())>"Сегодня"</button>
+            </Show>
+        </div>
+    }
+}
+
+/// Apostol and Gospel readings from the church calendar.
+#[component]
+fn ChurchReadings(date: RwSignal<NaiveDate>) -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let lections = Memo::new(move |_| calendar::lections(date.get(), state.calendar_style.get()));
+
+    view! {
+        <section class=common::section>
+            <h2 class=common::sectionTitle>"Церковный календарь"</h2>
+            <div class=common::tabs>
+                {CalendarStyle::ALL.into_iter().map(|style| view! {
+                    <button
+                        class=move || if state.calendar_style.get() == style {
+                            format!("{} {}", common::tab, common::tabActive)
+                        } else {
+                            common::tab.to_string()
+                        }
+                        on:click=move |_| state.calendar_style.set(style)
+                    >{style.label()}</button>
+                }).collect_view()}
+            </div>
+            <Show
+                when=move || lections.with(|l| !l.is_empty())
+                fallback=|| view! {
+                    <p class=common::textMuted>"В этот день Литургия не служится"</p>
+                }
+            >
+                <For
+                    each=move || lections.get()
+                    key=|lection| lection.title.clone()
+                    let:lection
+                >
+                    <LectionCard lection=lection/>
+                </For>
+            </Show>
+            <MoveableFeasts date=date/>
+        </section>
+    }
+}
+
+/// Moveable feasts of the selected year; picking one jumps to its date.
+#[component]
+fn MoveableFeasts(date: RwSignal<NaiveDate>) -> impl IntoView {
+    let year = Memo::new(move |_| date.get().year());
+
+    view! {
+        <h3 class=reading::lectionLabel>{move || format!("Переходящие праздники {} года", year.get())}</h3>
+        <ul class=reading::feasts>
+            {move || calendar::moveable_feasts(year.get()).into_iter().map(|(day, title)| view! {
+                <li>
+                    <button
+                        class=move || if date.get() == day {
+                            format!("{} {}", reading::feast, reading::feastActive)
+                        } else {
+                            reading::feast.to_string()
+                        }
+                        on:click=move |_| date.set(day)
+                    >
+                        <span class=reading::feastDate>{day.format("%d.%m").to_string()}</span>
+                        {title}
+                    </button>
+                </li>
+            }).collect_view()}
+        </ul>
+    }
+}
+
+/// One service: feast name with its Apostol and Gospel.
+#[component]
+fn LectionCard(lection: Lection) -> impl IntoView {
+    view! {
+        <div class=format!("{} {}", common::card, reading::lection)>
+            <span class=reading::lectionTitle>{lection.title}</span>
+            {(!lection.apostol.is_empty()).then(|| view! {
+                <span class=reading::lectionLabel>"Апостол"</span>
+                <Passages references=lection.apostol/>
+            })}
+            {(!lection.gospel.is_empty()).then(|| view! {
+                <span class=reading::lectionLabel>"Евангелие"</span>
+                <Passages references=lection.gospel/>
+            })}
+        </div>
+    }
+}
+
+/// References resolved against the cached Bible.
+#[component]
+fn Passages(references: Vec<Reference>) -> impl IntoView {
+    let state = expect_context::<AppState>();
+
+    references
+        .into_iter()
+        .map(|reference| view! {
+            <A
+                href=format!("/bible/{}/{}?verse={}", reference.book_id, reference.chapter, reference.verse.unwrap_or(1))
+                attr:class=reading::lectionRef
+            >
+                {reference.to_string()}
+            </A>
+            {move || state.bible.with(|bible| bible.as_ref().map(|bible| view! {
+                <VerseList verses=bible.get_passage(&reference)/>
+            }))}
+        })
+        .collect_view()
+}
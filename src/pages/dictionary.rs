@@ -0,0 +1,157 @@
+//! Bible dictionary and topical index - offline articles and passages
+
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_params_map};
+
+use crate::{
+    components::{BottomNav, Header, PassageList},
+    dictionary::{EntryKind, dictionary},
+    haptic,
+    maps::{Place, atlas}
+};
+
+#[allow(dead_code)]
+mod styles {
+    stylance::import_crate_style!(pub common, "src/styles/common.module.css");
+    stylance::import_crate_style!(pub dict, "src/styles/dictionary.module.css");
+}
+use styles::{common, dict};
+
+/// Dictionary index with search and kind tabs.
+#[must_use]
+#[component]
+pub fn Dictionary() -> impl IntoView {
+    let query = RwSignal::new(String::new());
+    let kind = RwSignal::new(None::<EntryKind>);
+
+    let entries = move || {
+        let query = query.get();
+        dictionary()
+            .search(&query, kind.get())
+            .into_iter()
+            .map(|entry| {
+                view! {
+                    <A href=format!("/dictionary/{}", entry.id) attr:class=dict::item>
+                        <span class=dict::itemName>{entry.name.clone()}</span>
+                        <span class=dict::itemSummary>{entry.summary.clone()}</span>
+                    </A>
+                }
+            })
+            .collect_view()
+    };
+
+    let tab = move |value: Option<EntryKind>, label: &'static str| {
+        view! {
+            <button
+                class=move || if kind.get() == value {
+                    format!("{} {}", common::tab, common::tabActive)
+                } else {
+                    common::tab.to_string()
+                }
+                on:click=move |_| { haptic::tap(); kind.set(value); }
+            >{label}</button>
+        }
+    };
+
+    view! {
+        <div class=common::page>
+            <Header title="Словарь"/>
+
+            <div class=common::container>
+                <input
+                    type="search"
+                    class=common::input
+                    placeholder="Имя, место или понятие"
+                    prop:value=query
+                    on:input=move |ev| query.set(event_target_value(&ev))
+                />
+                <div class=common::tabs>
+                    {tab(None, "Все")}
+                    {EntryKind::ALL.into_iter().map(|k| tab(Some(k), k.label())).collect_view()}
+                </div>
+                <div class=dict::list>{entries}</div>
+                <A href="/topics" attr:class=common::link>"Тематический указатель"</A>
+            </div>
+
+            <BottomNav/>
+        </div>
+    }
+}
+
+/// Dictionary article with its key passages.
+#[must_use]
+#[component]
+pub fn DictionaryArticle() -> impl IntoView {
+    let params = use_params_map();
+    let entry = move || params.with(|p| p.get("id").and_then(|id| dictionary().get(&id)));
+
+    let title = Signal::derive(move || entry().map_or("Словарь", |e| e.name.as_str()).to_string());
+
+    view! {
+        <div class=common::page>
+            <Header title=title back=true/>
+
+            <div class=common::container>
+                {move || entry().map_or_else(
+                    || view! {
+                        <div class=common::emptyState>
+                            <p class=common::textMuted>"Статья не найдена"</p>
+                        </div>
+                    }.into_any(),
+                    |entry| view! {
+                        <article class=dict::article>
+                            <span class=dict::kind>{entry.kind.label()}</span>
+                            <p class=dict::summary>{entry.summary.clone()}</p>
+                            {atlas().place(&entry.id).and_then(Place::href).map(|href| view! {
+                                <A href=href attr:class=common::link>"На карте"</A>
+                            })}
+                        </article>
+                        <PassageList references=entry.references()/>
+                    }.into_any()
+                )}
+            </div>
+
+            <BottomNav/>
+        </div>
+    }
+}
+
+/// Topical index, each topic expands to its passages.
+#[must_use]
+#[component]
+pub fn Topics() -> impl IntoView {
+    let open = RwSignal::new(None::<&'static str>);
+
+    view! {
+        <div class=common::page>
+            <Header title="Темы" back=true/>
+
+            <div class=common::container>
+                {dictionary().topics().iter().map(|topic| {
+                    let id = topic.id.as_str();
+                    let is_open = move || open.get() == Some(id);
+                    view! {
+                        <section class=dict::topic>
+                            <button
+                                class=dict::topicHead
+                                aria-expanded=move || is_open().to_string()
+                                on:click=move |_| {
+                                    haptic::tap();
+                                    open.update(|o| *o = if *o == Some(id) { None } else { Some(id) });
+                                }
+                            >
+                                <span class=dict::itemName>{topic.name.clone()}</span>
+                                <span class=dict::count>{topic.references.len()}</span>
+                            </button>
+                            <Show when=is_open>
+                                <PassageList references=topic.references()/>
+                            </Show>
+                        </section>
+                    }
+                }).collect_view()}
+            </div>
+
+            <BottomNav/>
+        </div>
+    }
+}
@@ -0,0 +1,88 @@
+//! Harmony of the Gospels - events with their accounts in each Gospel
+
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::{
+    components::{BottomNav, GospelAccounts, Header},
+    haptic,
+    harmony::{Gospel, harmony}
+};
+
+#[allow(dead_code)]
+mod styles {
+    stylance::import_crate_style!(pub common, "src/styles/common.module.css");
+    stylance::import_crate_style!(pub harmony, "src/styles/harmony.module.css");
+}
+use styles::{common, harmony as table};
+
+/// Harmony table; an event row expands to its texts side by side.
+#[must_use]
+#[component]
+pub fn Harmony() -> impl IntoView {
+    // Event open as `(section, event)`
+    let open = RwSignal::new(None::<(usize, usize)>);
+
+    view! {
+        <div class=common::page>
+            <Header title="Гармония Евангелий" back=true/>
+
+            <div class=common::container>
+                {harmony().sections.iter().enumerate().map(|(section_idx, section)| view! {
+                    <section class=table::section>
+                        <h2 class=table::sectionTitle>{section.title.clone()}</h2>
+                        <table class=table::table>
+                            <thead>
+                                <tr>
+                                    <th>"Событие"</th>
+                                    {Gospel::ALL.map(|g| view! { <th>{g.label()}</th> })}
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {section.events.iter().enumerate().map(|(idx, event)| {
+                                    let key = (section_idx, idx);
+                                    let is_open = move || open.get() == Some(key);
+                                    view! {
+                                        <tr class=move || if is_open() { table::rowOpen } else { "" }>
+                                            <td>
+                                                <button
+                                                    class=table::rowTitle
+                                                    aria-expanded=move || is_open().to_string()
+                                                    on:click=move |_| {
+                                                        haptic::tap();
+                                                        open.update(|o| *o = if *o == Some(key) { None } else { Some(key) });
+                                                    }
+                                                >{event.title.clone()}</button>
+                                            </td>
+                                            {Gospel::ALL.map(|g| view! {
+                                                <td>
+                                                    {event.passage(g).map(|r| view! {
+                                                        <A
+                                                            href=format!("/bible/{}/{}?verse={}", r.book_id, r.chapter, r.verse.unwrap_or(1))
+                                                            attr:class=table::cell
+                                                        >
+                                                            {r.to_string().split_once(' ').map_or_else(String::new, |(_, rest)| rest.to_string())}
+                                                        </A>
+                                                    })}
+                                                </td>
+                                            })}
+                                        </tr>
+                                        <Show when=is_open>
+                                            <tr>
+                                                <td colspan="5" class=table::expanded>
+                                                    <GospelAccounts event=event/>
+                                                </td>
+                                            </tr>
+                                        </Show>
+                                    }
+                                }).collect_view()}
+                            </tbody>
+                        </table>
+                    </section>
+                }).collect_view()}
+            </div>
+
+            <BottomNav/>
+        </div>
+    }
+}
@@ -0,0 +1,295 @@
+//! Biblical maps - offline SVG maps with places linked to passages
+
+use leptos::prelude::*;
+use leptos_router::{
+    components::A,
+    hooks::{use_params_map, use_query_map}
+};
+
+use crate::{
+    components::{BottomNav, Header, PassageList},
+    haptic,
+    maps::{BibleMap, FeatureKind, Geometry, Place, Projection, Route, VIEW_WIDTH, atlas}
+};
+
+#[allow(dead_code)]
+mod styles {
+    stylance::import_crate_style!(pub common, "src/styles/common.module.css");
+    stylance::import_crate_style!(pub map, "src/styles/maps.module.css");
+}
+use styles::{common, map};
+
+/// Territory fills, cycled in file order so neighbouring allotments differ.
+const TERRITORY_COLORS: [&str; 6] = [
+    "#c0392b", "#d68910", "#1e8449", "#2874a6", "#7d3c98", "#a04000"
+];
+
+/// Route line colors, cycled in legend order.
+const ROUTE_COLORS: [&str; 4] = ["#c0392b", "#2874a6", "#1e8449", "#7d3c98"];
+
+/// Zoom range of the map.
+const ZOOM: (f64, f64) = (1.0, 4.0);
+
+/// Label size in view box units.
+const LABEL_SIZE: f64 = VIEW_WIDTH / 60.0;
+
+/// What the info panel shows.
+#[derive(Clone, Copy, PartialEq)]
+enum Selected {
+    Place(&'static Place),
+    Route(usize, &'static Route)
+}
+
+/// List of maps.
+#[must_use]
+#[component]
+pub fn Maps() -> impl IntoView {
+    view! {
+        <div class=common::page>
+            <Header title="Карты"/>
+
+            <div class=common::container>
+                <div class=map::list>
+                    {atlas().maps().iter().map(|m| view! {
+                        <A href=format!("/maps/{}", m.id) attr:class=map::item>
+                            <span class=map::itemTitle>{m.title.clone()}</span>
+                            <span class=map::itemDesc>{m.description.clone()}</span>
+                        </A>
+                    }).collect_view()}
+                </div>
+            </div>
+
+            <BottomNav/>
+        </div>
+    }
+}
+
+/// One map; `?place=` selects a place on open.
+#[must_use]
+#[component]
+pub fn MapView() -> impl IntoView {
+    let params = use_params_map();
+    let query = use_query_map();
+
+    let bible_map = move || params.with(|p| p.get("id").and_then(|id| atlas().get(&id)));
+    let title = Signal::derive(move || {
+        bible_map()
+            .map_or("Карта", |m| m.title.as_str())
+            .to_string()
+    });
+
+    let selected = RwSignal::new(None::<Selected>);
+    Effect::new(move |_| {
+        let place = query.with(|q| q.get("place").and_then(|id| atlas().place(&id)));
+        selected.set(place.map(Selected::Place));
+    });
+
+    view! {
+        <div class=common::page>
+            <Header title=title back=true/>
+
+            <div class=common::container>
+                {move || bible_map().map_or_else(
+                    || view! {
+                        <div class=common::emptyState>
+                            <p class=common::textMuted>"Карта не найдена"</p>
+                        </div>
+                    }.into_any(),
+                    |m| view! {
+                        <MapCanvas bible_map=m selected=selected/>
+                        <RouteLegend bible_map=m selected=selected/>
+                        <SelectionInfo selected=selected/>
+                    }.into_any()
+                )}
+            </div>
+
+            <BottomNav/>
+        </div>
+    }
+}
+
+/// The SVG map with zoom controls.
+#[component]
+fn MapCanvas(bible_map: &'static BibleMap, selected: RwSignal<Option<Selected>>) -> impl IntoView {
+    let id = bible_map.id.as_str();
+    let projection = Projection::new(bible_map.bounds);
+    let zoom = RwSignal::new(ZOOM.0);
+
+    let shapes = atlas()
+        .shapes(id)
+        .enumerate()
+        .map(|(idx, shape)| match (&shape.geometry, shape.kind) {
+            (Geometry::Point(position), _) => {
+                let (x, y) = projection.point(*position);
+                view! {
+                    <text class=map::region x=x y=y font-size=LABEL_SIZE text-anchor="middle">
+                        {shape.name.clone()}
+                    </text>
+                }
+                .into_any()
+            }
+            (geometry, FeatureKind::Territory) => view! {
+                <path
+                    class=map::territory
+                    style:fill=TERRITORY_COLORS[idx % TERRITORY_COLORS.len()]
+                    d=projection.geometry(geometry)
+                >
+                    <title>{shape.name.clone()}</title>
+                </path>
+            }
+            .into_any(),
+            (geometry, kind) => {
+                let class = match kind {
+                    FeatureKind::Island => map::island,
+                    FeatureKind::River => map::river,
+                    _ => map::water
+                };
+                view! {
+                    <path class=class d=projection.geometry(geometry)>
+                        <title>{shape.name.clone()}</title>
+                    </path>
+                }
+                .into_any()
+            }
+        })
+        .collect_view();
+
+    let routes = atlas()
+        .routes(id)
+        .enumerate()
+        .map(|(idx, route)| {
+            let is_selected =
+                move || matches!(selected.get(), Some(Selected::Route(i, _)) if i == idx);
+            view! {
+                <path
+                    class=move || if is_selected() {
+                        format!("{} {}", map::route, map::routeSelected)
+                    } else {
+                        map::route.to_string()
+                    }
+                    stroke=ROUTE_COLORS[idx % ROUTE_COLORS.len()]
+                    d=projection.path(&route.points, false)
+                    on:click=move |_| selected.set(Some(Selected::Route(idx, route)))
+                />
+            }
+        })
+        .collect_view();
+
+    let places = atlas()
+        .places(id)
+        .map(|place| {
+            let (x, y) = projection.point(place.position);
+            let is_selected = move || selected.get() == Some(Selected::Place(place));
+            view! {
+                <g
+                    class=move || if is_selected() {
+                        format!("{} {}", map::place, map::placeSelected)
+                    } else {
+                        map::place.to_string()
+                    }
+                    on:click=move |_| {
+                        haptic::tap();
+                        selected.set(Some(Selected::Place(place)));
+                    }
+                >
+                    <circle cx=x cy=y r=LABEL_SIZE / 3.0/>
+                    <text x=x + LABEL_SIZE / 2.0 y=y + LABEL_SIZE / 3.0 font-size=LABEL_SIZE>
+                        {place.name.clone()}
+                    </text>
+                </g>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div class=map::canvas>
+            <div class=map::scroll>
+                <svg
+                    class=map::svg
+                    viewBox=projection.view_box()
+                    style:width=move || format!("{}%", zoom.get() * 100.0)
+                    role="img"
+                    aria-label=bible_map.title.clone()
+                >
+                    <rect class=map::land width="100%" height="100%"/>
+                    {shapes}
+                    {routes}
+                    {places}
+                </svg>
+            </div>
+            <div class=map::zoom>
+                <button
+                    class=map::zoomBtn
+                    aria-label="Приблизить"
+                    on:click=move |_| zoom.update(|z| *z = (*z * 1.5).min(ZOOM.1))
+                >"+"</button>
+                <button
+                    class=map::zoomBtn
+                    aria-label="Отдалить"
+                    on:click=move |_| zoom.update(|z| *z = (*z / 1.5).max(ZOOM.0))
+                >"−"</button>
+            </div>
+        </div>
+    }
+}
+
+/// Route names in their line colors.
+#[component]
+fn RouteLegend(
+    bible_map: &'static BibleMap,
+    selected: RwSignal<Option<Selected>>
+) -> impl IntoView {
+    atlas()
+        .routes(&bible_map.id)
+        .enumerate()
+        .map(|(idx, route)| {
+            view! {
+                <button
+                    class=map::legendItem
+                    on:click=move |_| {
+                        haptic::tap();
+                        selected.set(Some(Selected::Route(idx, route)));
+                    }
+                >
+                    <span
+                        class=map::swatch
+                        style:background=ROUTE_COLORS[idx % ROUTE_COLORS.len()]
+                    />
+                    {route.name.clone()}
+                </button>
+            }
+        })
+        .collect_view()
+}
+
+/// Passages about the selected place or route.
+#[component]
+fn SelectionInfo(selected: RwSignal<Option<Selected>>) -> impl IntoView {
+    move || {
+        match selected.get() {
+        Some(Selected::Place(place)) => view! {
+            <section class=map::info>
+                <div class=map::infoHead>
+                    <h2 class=map::infoTitle>{place.name.clone()}</h2>
+                    {place.entry.clone().map(|entry| view! {
+                        <A href=format!("/dictionary/{entry}") attr:class=common::link>"Статья в словаре"</A>
+                    })}
+                </div>
+                <PassageList references=place.references()/>
+            </section>
+        }
+        .into_any(),
+        Some(Selected::Route(_, route)) => view! {
+            <section class=map::info>
+                <h2 class=map::infoTitle>{route.name.clone()}</h2>
+                <PassageList references=route.references()/>
+            </section>
+        }
+        .into_any(),
+        None => view! {
+            <p class=common::textMuted>"Нажмите на место или маршрут, чтобы прочитать о нём"</p>
+        }
+        .into_any()
+    }
+    }
+}
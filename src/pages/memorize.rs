@@ -0,0 +1,346 @@
+//! Memorization trainer - verse deck and spaced repetition practice
+
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::{
+    components::{BottomNav, Header},
+    daily, haptic,
+    memorize::{
+        self, CheckedWord, MemoryCard, MemoryDeck, PracticeMode, first_letters, recall_quality
+    },
+    reference::Reference,
+    state::AppState
+};
+
+#[allow(dead_code)]
+mod styles {
+    stylance::import_crate_style!(pub common, "src/styles/common.module.css");
+    stylance::import_crate_style!(pub memo, "src/styles/memorize.module.css");
+}
+use styles::{common, memo};
+
+/// Self-assessment buttons with their SM-2 quality.
+const GRADES: [(&str, u8); 4] = [("Забыл", 1), ("Трудно", 3), ("Хорошо", 4), ("Легко", 5)];
+
+#[must_use]
+#[component]
+pub fn Memorize() -> impl IntoView {
+    let cards = RwSignal::new(MemoryDeck::load());
+    Effect::new(move |_| cards.with(|cards| MemoryDeck::save(cards)));
+
+    // References left in the current review session
+    let queue = RwSignal::new(Vec::<String>::new());
+    let current = Memo::new(move |_| {
+        let reference = queue.with(|q| q.first().cloned())?;
+        cards.with(|cards| cards.iter().find(|c| c.reference == reference).cloned())
+    });
+
+    let due = move || {
+        let today = daily::today();
+        cards.with(|cards| {
+            cards
+                .iter()
+                .filter(|c| c.is_due(today))
+                .map(|c| c.reference.clone())
+                .collect::<Vec<_>>()
+        })
+    };
+
+    let on_grade = Callback::new(move |quality: u8| {
+        let Some(card) = current.get_untracked() else {
+            return;
+        };
+        cards.update(|cards| {
+            if let Some(c) = cards.iter_mut().find(|c| c.reference == card.reference) {
+                c.review(quality, daily::today());
+            }
+        });
+        // Forgotten verses come back at the end of the session
+        queue.update(|q| {
+            q.remove(0);
+            if quality < 3 {
+                q.push(card.reference);
+            }
+        });
+    });
+
+    view! {
+        <div class=common::page>
+            <Header title="Заучивание" back=true/>
+
+            <div class=common::container>
+                {move || match current.get() {
+                    Some(card) => view! {
+                        <Practice card=card on_grade=on_grade/>
+                        <button class=common::btnGhost on:click=move |_| queue.set(Vec::new())>
+                            "Завершить"
+                        </button>
+                    }.into_any(),
+                    None => view! {
+                        <AddVerse cards=cards/>
+                        <button
+                            class=common::btnPrimary
+                            disabled=move || due().is_empty()
+                            on:click=move |_| { haptic::tap(); queue.set(due()); }
+                        >
+                            {move || match due().len() {
+                                0 => "Сегодня повторять нечего".to_string(),
+                                n => format!("Повторить ({n})")
+                            }}
+                        </button>
+                        <CardList cards=cards/>
+                    }.into_any()
+                }}
+            </div>
+
+            <BottomNav/>
+        </div>
+    }
+}
+
+/// Adds a verse by typed reference.
+#[component]
+fn AddVerse(cards: RwSignal<Vec<MemoryCard>>) -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let input = RwSignal::new(String::new());
+    let error = RwSignal::new(None::<&'static str>);
+
+    let on_add = move |_| {
+        let Some(reference) = Reference::parse(&input.get_untracked()) else {
+            error.set(Some("Не удалось разобрать ссылку"));
+            return;
+        };
+        let verses = state
+            .bible
+            .with_untracked(|bible| bible.as_ref().map(|b| b.get_passage(&reference)))
+            .unwrap_or_default();
+        let Some(mut card) = MemoryCard::from_verses(&verses, daily::today()) else {
+            error.set(Some("Стихи не найдены"));
+            return;
+        };
+        card.reference = reference.to_string();
+        if cards.with_untracked(|cards| cards.iter().any(|c| c.reference == card.reference)) {
+            error.set(Some("Этот отрывок уже в списке"));
+            return;
+        }
+        haptic::success();
+        cards.update(|cards| cards.push(card));
+        input.set(String::new());
+        error.set(None);
+    };
+
+    view! {
+        <section class=common::section>
+            <div class=memo::addForm>
+                <input
+                    class=common::input
+                    placeholder="Например, Ин 3:16"
+                    prop:value=input
+                    on:input=move |ev| input.set(event_target_value(&ev))
+                />
+                <button class=common::btnPrimary on:click=on_add>"Добавить"</button>
+            </div>
+            {move || error.get().map(|e| view! { <span class=memo::error>{e}</span> })}
+        </section>
+    }
+}
+
+/// Verses in the deck with their next review date.
+#[component]
+fn CardList(cards: RwSignal<Vec<MemoryCard>>) -> impl IntoView {
+    let due_label = |card: &MemoryCard| {
+        let days = (card.due - daily::today()).num_days();
+        if days <= 0 {
+            "Повторить сегодня".to_string()
+        } else {
+            format!("Повторение через {days} дн.")
+        }
+    };
+
+    view! {
+        <section class=common::section>
+            <h2 class=common::sectionTitle>"Мои стихи"</h2>
+            <Show
+                when=move || cards.with(|c| !c.is_empty())
+                fallback=|| view! {
+                    <p class=common::textMuted>
+                        "Добавьте отрывок по ссылке или выделите стихи в Библии и нажмите «Выучить»"
+                    </p>
+                }
+            >
+                <For
+                    each=move || cards.get()
+                    key=|card| card.reference.clone()
+                    let:card
+                >
+                    {
+                        let reference = card.reference.clone();
+                        let due = due_label(&card);
+                        view! {
+                            <div class=memo::card>
+                                <div class=memo::cardInfo>
+                                    <A
+                                        href=format!("/bible/{}/{}?verse={}", card.book_id, card.chapter, card.verse)
+                                        attr:class=memo::cardReference
+                                    >
+                                        {card.reference}
+                                    </A>
+                                    <span class=memo::cardText>{card.text}</span>
+                                    <span class=memo::cardDue>{due}</span>
+                                </div>
+                                <button
+                                    class=memo::remove
+                                    aria-label="Удалить"
+                                    on:click=move |_| cards.update(|cards| cards.retain(|c| c.reference != reference))
+                                >"✕"</button>
+                            </div>
+                        }
+                    }
+                </For>
+            </Show>
+        </section>
+    }
+}
+
+/// Practice screen for one card.
+#[component]
+fn Practice(card: MemoryCard, on_grade: Callback<u8>) -> impl IntoView {
+    let mode = RwSignal::new(PracticeMode::default());
+    let text = StoredValue::new(card.text);
+    let repetitions = card.repetitions;
+
+    view! {
+        <div class=memo::practice>
+            <span class=memo::practiceReference>{card.reference}</span>
+            <div class=common::tabs>
+                {PracticeMode::ALL.into_iter().map(|m| view! {
+                    <button
+                        class=move || if mode.get() == m {
+                            format!("{} {}", common::tab, common::tabActive)
+                        } else {
+                            common::tab.to_string()
+                        }
+                        on:click=move |_| mode.set(m)
+                    >{m.label()}</button>
+                }).collect_view()}
+            </div>
+            {move || match mode.get() {
+                PracticeMode::FirstLetters => view! {
+                    <FirstLetters text=text.get_value() on_grade=on_grade/>
+                }.into_any(),
+                PracticeMode::Blanks => view! {
+                    <Blanks text=text.get_value() repetitions=repetitions on_grade=on_grade/>
+                }.into_any(),
+                PracticeMode::Recall => view! {
+                    <Recall text=text.get_value() on_grade=on_grade/>
+                }.into_any()
+            }}
+        </div>
+    }
+}
+
+/// First letter hints with an option to peek at the text.
+#[component]
+fn FirstLetters(text: String, on_grade: Callback<u8>) -> impl IntoView {
+    let revealed = RwSignal::new(false);
+    let hints = first_letters(&text);
+
+    view! {
+        <p class=memo::practiceText>
+            {move || if revealed.get() { text.clone() } else { hints.clone() }}
+        </p>
+        <button class=common::btnSecondary on:click=move |_| revealed.update(|r| *r = !*r)>
+            {move || if revealed.get() { "Скрыть текст" } else { "Показать текст" }}
+        </button>
+        <SelfGrade on_grade=on_grade/>
+    }
+}
+
+/// Text with hidden words revealed on tap.
+#[component]
+fn Blanks(text: String, repetitions: u32, on_grade: Callback<u8>) -> impl IntoView {
+    view! {
+        <p class=memo::practiceText>
+            {memorize::blanks(&text, repetitions).into_iter().map(|token| {
+                if token.hidden {
+                    let revealed = RwSignal::new(false);
+                    view! {
+                        <span
+                            class=move || if revealed.get() {
+                                format!("{} {}", memo::blank, memo::revealed)
+                            } else {
+                                memo::blank.to_string()
+                            }
+                            on:click=move |_| revealed.set(true)
+                        >{token.word}</span>
+                        " "
+                    }.into_any()
+                } else {
+                    view! { {token.word} " " }.into_any()
+                }
+            }).collect_view()}
+        </p>
+        <SelfGrade on_grade=on_grade/>
+    }
+}
+
+/// Typing the passage from memory, checked word by word.
+#[component]
+fn Recall(text: String, on_grade: Callback<u8>) -> impl IntoView {
+    let typed = RwSignal::new(String::new());
+    let checked = RwSignal::new(None::<Vec<CheckedWord>>);
+
+    let on_check = move |_| {
+        checked.set(Some(memorize::check_recall(&text, &typed.get_untracked())));
+    };
+
+    view! {
+        {move || checked.get().map_or_else(
+            || view! {
+                <textarea
+                    class=common::textarea
+                    placeholder="Напишите отрывок по памяти"
+                    prop:value=typed
+                    on:input=move |ev| typed.set(event_target_value(&ev))
+                />
+                <button class=common::btnPrimary on:click=on_check.clone()>"Проверить"</button>
+            }.into_any(),
+            |words| {
+                let quality = recall_quality(&words);
+                let correct = words.iter().filter(|w| w.correct).count();
+                let percent = correct * 100 / words.len().max(1);
+                view! {
+                    <p class=memo::practiceText>
+                        {words.into_iter().map(|w| view! {
+                            <span class=(memo::wrong, !w.correct)>{w.word}</span>
+                            " "
+                        }).collect_view()}
+                    </p>
+                    <span class=memo::score>{format!("Точность {percent}%")}</span>
+                    <button class=common::btnPrimary on:click=move |_| on_grade.run(quality)>
+                        "Далее"
+                    </button>
+                }.into_any()
+            }
+        )}
+    }
+}
+
+/// Self-assessment after a hint-based practice.
+#[component]
+fn SelfGrade(on_grade: Callback<u8>) -> impl IntoView {
+    view! {
+        <div class=memo::grades>
+            {GRADES.into_iter().map(|(label, quality)| view! {
+                <button
+                    class=common::btnSecondary
+                    on:click=move |_| {
+                        haptic::tap();
+                        on_grade.run(quality);
+                    }
+                >{label}</button>
+            }).collect_view()}
+        </div>
+    }
+}
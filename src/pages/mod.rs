@@ -1,25 +1,43 @@
 #![allow(ambiguous_glob_reexports)]
 
 mod bible;
+mod book;
 mod churches;
 mod daily;
+mod dictionary;
 mod feed;
+mod harmony;
 mod home;
+mod maps;
+mod memorize;
 mod not_found;
 mod onboarding;
+mod plans;
+mod present;
+mod print;
 mod profile;
 mod search;
 mod settings;
 mod songs;
+mod stats;
 
 pub use bible::*;
+pub use book::*;
 pub use churches::*;
 pub use daily::*;
+pub use dictionary::*;
 pub use feed::*;
+pub use harmony::*;
 pub use home::*;
+pub use maps::*;
+pub use memorize::*;
 pub use not_found::*;
 pub use onboarding::*;
+pub use plans::*;
+pub use present::*;
+pub use print::*;
 pub use profile::*;
 pub use search::*;
 pub use settings::*;
 pub use songs::*;
+pub use stats::*;
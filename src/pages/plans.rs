@@ -0,0 +1,371 @@
+//! Reading plans - plan list and day-by-day reading
+
+use chrono::NaiveDate;
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_params_map};
+
+use crate::{
+    components::{BottomNav, Header, Loading, VerseList},
+    daily::today,
+    haptic,
+    plans::{PlanKind, PlanProgress, Plans},
+    reference::Reference,
+    state::AppState
+};
+
+#[allow(dead_code)]
+mod styles {
+    stylance::import_crate_style!(pub common, "src/styles/common.module.css");
+    stylance::import_crate_style!(pub plan, "src/styles/plans.module.css");
+}
+use styles::{common, plan};
+
+/// Started plans, saved back to storage on every change.
+fn use_plans() -> RwSignal<Vec<PlanProgress>> {
+    let plans = RwSignal::new(Plans::load());
+    Effect::new(move |_| plans.with(|plans| Plans::save(plans)));
+    plans
+}
+
+/// Applies `f` to the progress of a started plan.
+fn update_plan(
+    plans: RwSignal<Vec<PlanProgress>>,
+    kind: PlanKind,
+    f: impl FnOnce(&mut PlanProgress)
+) {
+    plans.update(|plans| {
+        if let Some(progress) = plans.iter_mut().find(|p| p.kind == kind) {
+            f(progress);
+        }
+    });
+}
+
+/// Formats a day index with its calendar date.
+fn day_label(progress: &PlanProgress, day: u16) -> String {
+    format!(
+        "День {} · {}",
+        day + 1,
+        progress.date_of(day).format("%d.%m.%Y")
+    )
+}
+
+#[must_use]
+#[component]
+pub fn ReadingPlans() -> impl IntoView {
+    let plans = use_plans();
+
+    view! {
+        <div class=common::page>
+            <Header title="Планы чтения"/>
+
+            <div class=common::container>
+                {PlanKind::ALL.into_iter().map(|kind| view! {
+                    {move || {
+                        let started = plans.with(|plans| plans.iter().any(|p| p.kind == kind));
+                        if started {
+                            view! { <PlanCard kind=kind plans=plans/> }.into_any()
+                        } else {
+                            view! { <StartPlan kind=kind plans=plans/> }.into_any()
+                        }
+                    }}
+                }).collect_view()}
+            </div>
+
+            <BottomNav/>
+        </div>
+    }
+}
+
+/// Overview of a started plan.
+#[component]
+fn PlanCard(kind: PlanKind, plans: RwSignal<Vec<PlanProgress>>) -> impl IntoView {
+    let progress =
+        Memo::new(move |_| plans.with(|plans| plans.iter().find(|p| p.kind == kind).cloned()));
+    let percent = move || progress.with(|p| p.as_ref().map_or(0, PlanProgress::percent));
+    let missed = move || progress.with(|p| p.as_ref().map_or(0, |p| p.missed(today()).len()));
+
+    view! {
+        <div class=plan::plan>
+            <div class=plan::planHeader>
+                <A href=format!("/plans/{}", kind.slug()) attr:class=plan::planTitle>
+                    {kind.title()}
+                </A>
+                <span class=plan::planMeta>{move || format!("{}%", percent())}</span>
+            </div>
+            <div class=plan::progress>
+                <div
+                    class=plan::progressFill
+                    style:width=move || format!("{}%", percent())
+                />
+            </div>
+            <span class=plan::planMeta>
+                {move || progress.with(|p| p.as_ref().map(|p| if p.is_finished() {
+                    "План завершён".to_string()
+                } else {
+                    day_label(p, p.day_for(today()))
+                }))}
+            </span>
+            <Show when=move || { missed() > 0 }>
+                <div class=plan::behind>
+                    <span>{move || format!("Пропущено дней: {}", missed())}</span>
+                    <button
+                        class=common::btnGhost
+                        on:click=move |_| {
+                            haptic::tap();
+                            update_plan(plans, kind, |p| p.catch_up(today()));
+                        }
+                    >"Сдвинуть план"</button>
+                </div>
+            </Show>
+        </div>
+    }
+}
+
+/// Plan description with a start date picker.
+#[component]
+fn StartPlan(kind: PlanKind, plans: RwSignal<Vec<PlanProgress>>) -> impl IntoView {
+    let start = RwSignal::new(today());
+
+    let on_start = move |_| {
+        haptic::success();
+        let progress = PlanProgress::new(kind, start.get_untracked());
+        plans.update(|plans| {
+            plans.retain(|p| p.kind != kind);
+            plans.push(progress);
+        });
+    };
+
+    view! {
+        <div class=plan::plan>
+            <span class=plan::planTitle>{kind.title()}</span>
+            <span class=plan::planMeta>{kind.description()}</span>
+            <div class=plan::start>
+                <input
+                    type="date"
+                    class=plan::dateInput
+                    aria-label="Дата начала"
+                    prop:value=move || start.get().format("%Y-%m-%d").to_string()
+                    on:change=move |ev| {
+                        if let Ok(date) = NaiveDate::parse_from_str(&event_target_value(&ev), "%Y-%m-%d") {
+                            start.set(date);
+                        }
+                    }
+                />
+                <button class=common::btnPrimary on:click=on_start>"Начать"</button>
+            </div>
+        </div>
+    }
+}
+
+#[must_use]
+#[component]
+pub fn PlanDetail() -> impl IntoView {
+    let params = use_params_map();
+    let state = expect_context::<AppState>();
+    let plans = use_plans();
+
+    let kind =
+        Memo::new(move |_| params.with(|p| p.get("id").and_then(|id| PlanKind::from_slug(&id))));
+    let progress = Memo::new(move |_| {
+        let kind = kind.get()?;
+        plans.with(|plans| plans.iter().find(|p| p.kind == kind).cloned())
+    });
+    let schedule = Memo::new(move |_| {
+        let kind = kind.get()?;
+        state.bible.with(|bible| {
+            bible
+                .as_ref()
+                .map(|bible| kind.schedule(&bible.get_books()))
+        })
+    });
+    let selected = RwSignal::new(None::<u16>);
+    // Open on today's reading, or on the first missed day
+    let day = Signal::derive(move || {
+        selected.get().or_else(|| {
+            progress.with(|p| {
+                p.as_ref().map(|p| {
+                    let today = today();
+                    p.missed(today)
+                        .first()
+                        .copied()
+                        .unwrap_or_else(|| p.day_for(today))
+                })
+            })
+        })
+    });
+
+    let title = Signal::derive(move || {
+        kind.get()
+            .map_or("План чтения", PlanKind::title)
+            .to_string()
+    });
+
+    view! {
+        <div class=common::page>
+            <Header title=title back=true/>
+
+            <div class=common::container>
+                {move || match (kind.get(), progress.get()) {
+                    (None, _) => view! {
+                        <div class=common::emptyState>
+                            <p class=common::textMuted>"План не найден"</p>
+                        </div>
+                    }.into_any(),
+                    (Some(kind), None) => view! { <StartPlan kind=kind plans=plans/> }.into_any(),
+                    (Some(kind), Some(_)) => view! {
+                        <Show
+                            when=move || schedule.with(Option::is_some)
+                            fallback=|| view! { <Loading/> }
+                        >
+                            <PlanDay kind=kind plans=plans progress=progress schedule=schedule day=day selected=selected/>
+                            <PlanGrid progress=progress day=day selected=selected/>
+                            <button
+                                class=common::btnGhost
+                                on:click=move |_| plans.update(|plans| plans.retain(|p| p.kind != kind))
+                            >"Остановить план"</button>
+                        </Show>
+                    }.into_any()
+                }}
+            </div>
+
+            <BottomNav/>
+        </div>
+    }
+}
+
+/// Passages of one plan day with the completion toggle.
+#[component]
+fn PlanDay(
+    kind: PlanKind,
+    plans: RwSignal<Vec<PlanProgress>>,
+    progress: Memo<Option<PlanProgress>>,
+    schedule: Memo<Option<Vec<Vec<Reference>>>>,
+    day: Signal<Option<u16>>,
+    selected: RwSignal<Option<u16>>
+) -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let current = move || day.get().unwrap_or(0);
+    let done =
+        move || progress.with(|p| p.as_ref().is_some_and(|p| p.completed.contains(&current())));
+
+    let passages = move || {
+        schedule.with(|s| {
+            s.as_ref()
+                .and_then(|s| s.get(usize::from(current())).cloned())
+                .unwrap_or_default()
+        })
+    };
+
+    let step = move |delta: i32| {
+        let next = i32::from(current()) + delta;
+        if let Ok(next) = u16::try_from(next)
+            && next < kind.days()
+        {
+            haptic::tap();
+            selected.set(Some(next));
+        }
+    };
+
+    view! {
+        <div class=plan::dayNav>
+            <button class=common::btnGhost aria-label="Предыдущий день" on:click=move |_| step(-1)>"‹"</button>
+            <span class=plan::dayTitle>
+                {move || progress.with(|p| p.as_ref().map(|p| day_label(p, current())))}
+            </span>
+            <button class=common::btnGhost aria-label="Следующий день" on:click=move |_| step(1)>"›"</button>
+        </div>
+
+        <For
+            each=passages
+            key=|reference| reference.to_string()
+            let:reference
+        >
+            <div class=format!("{} {}", common::card, plan::passage)>
+                <A
+                    href=format!("/bible/{}/{}", reference.book_id, reference.chapter)
+                    attr:class=plan::passageTitle
+                >
+                    {reference.to_string()}
+                </A>
+                {move || state.bible.with(|bible| bible.as_ref().map(|bible| view! {
+                    <VerseList verses=bible.get_passage(&reference)/>
+                }))}
+            </div>
+        </For>
+
+        <button
+            class=move || if done() {
+                format!("{} {} {}", common::btnSecondary, plan::check, plan::checkDone)
+            } else {
+                format!("{} {}", common::btnPrimary, plan::check)
+            }
+            on:click=move |_| {
+                let day = current();
+                update_plan(plans, kind, |p| p.toggle(day));
+                if done() { haptic::success() } else { haptic::tap() }
+            }
+        >
+            {move || if done() { "✓ Прочитано" } else { "Отметить прочитанным" }}
+        </button>
+    }
+}
+
+/// Grid of all plan days: read, missed and today.
+#[component]
+fn PlanGrid(
+    progress: Memo<Option<PlanProgress>>,
+    day: Signal<Option<u16>>,
+    selected: RwSignal<Option<u16>>
+) -> impl IntoView {
+    let cells = move || {
+        progress.with(|p| {
+            let Some(p) = p else {
+                return Vec::new();
+            };
+            let today = p.day_for(today());
+            let missed = p.missed(self::today());
+            (0..p.kind.days())
+                .map(|d| {
+                    let mut class = plan::cell.to_string();
+                    if p.completed.contains(&d) {
+                        class = format!("{class} {}", plan::cellDone);
+                    } else if missed.contains(&d) {
+                        class = format!("{class} {}", plan::cellMissed);
+                    }
+                    if d == today {
+                        class = format!("{class} {}", plan::cellToday);
+                    }
+                    (d, class)
+                })
+                .collect()
+        })
+    };
+
+    view! {
+        <section class=common::section>
+            <h2 class=common::sectionTitle>"Прогресс"</h2>
+            <div class=plan::grid>
+                <For
+                    each=cells
+                    key=|cell| cell.clone()
+                    let:cell
+                >
+                    {
+                        let (d, class) = cell;
+                        view! {
+                            <button
+                                class=move || if day.get() == Some(d) {
+                                    format!("{class} {}", plan::cellSelected)
+                                } else {
+                                    class.clone()
+                                }
+                                title=format!("День {}", d + 1)
+                                on:click=move |_| selected.set(Some(d))
+                            />
+                        }
+                    }
+                </For>
+            </div>
+        </section>
+    }
+}
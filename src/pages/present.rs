@@ -0,0 +1,294 @@
+//! Projector presentation - control window with preview and the audience
+//! screen
+
+use leptos::{ev, prelude::*};
+use wasm_bindgen::JsCast;
+
+use crate::{
+    components::Header,
+    haptic,
+    presentation::{Presentation, Slide, SlideMode, slides},
+    reference::Reference,
+    state::AppState,
+    verse_image::Background
+};
+
+#[allow(dead_code)]
+mod styles {
+    stylance::import_crate_style!(pub common, "src/styles/common.module.css");
+    stylance::import_crate_style!(pub present, "src/styles/present.module.css");
+}
+use styles::{common, present};
+
+/// Path of the audience screen.
+const SCREEN_PATH: &str = "/present/screen";
+/// Window name, so the screen is reused instead of opened twice.
+const SCREEN_WINDOW: &str = "revelation-screen";
+
+/// Control window: passage, options, preview of the current and next slide.
+#[must_use]
+#[component]
+pub fn Present() -> impl IntoView {
+    let (presentation, slides) = use_presentation();
+    let input = RwSignal::new(presentation.with_untracked(|p| p.reference.clone()));
+
+    let current = move || {
+        let index = presentation.with(|p| p.index);
+        slides.with(|s| s.get(index).cloned())
+    };
+    let next = move || {
+        let index = presentation.with(|p| p.index);
+        slides.with(|s| s.get(index + 1).cloned())
+    };
+
+    let on_show = move || {
+        let value = input.get_untracked();
+        if Reference::parse(&value).is_some() {
+            haptic::tap();
+            presentation.update(|p| {
+                p.reference = value;
+                p.index = 0;
+            });
+        }
+    };
+
+    let open_screen = move |_| {
+        let _ = window().open_with_url_and_target(SCREEN_PATH, SCREEN_WINDOW);
+    };
+
+    view! {
+        <div class=common::page>
+            <Header title="Проектор" back=true/>
+
+            <div class=common::container>
+                <section class=common::section>
+                    <div class=present::form>
+                        <input
+                            class=common::input
+                            placeholder="Например, Ин 3:16-21"
+                            prop:value=input
+                            on:input=move |ev| input.set(event_target_value(&ev))
+                            on:keydown=move |ev| if ev.key() == "Enter" { on_show(); }
+                        />
+                        <button class=common::btnPrimary on:click=move |_| on_show()>"Показать"</button>
+                    </div>
+                    <PresentOptions presentation=presentation/>
+                </section>
+
+                <section class=common::section>
+                    <div class=present::previews>
+                        <div class=present::preview>
+                            <span class=common::label>
+                                {move || format!(
+                                    "Сейчас {} из {}",
+                                    presentation.with(|p| p.index) + 1,
+                                    slides.with(Vec::len).max(1)
+                                )}
+                            </span>
+                            <SlideView
+                                slide=Signal::derive(current)
+                                background=Signal::derive(move || presentation.with(|p| p.background))
+                                blank=Signal::derive(move || presentation.with(|p| p.blank))
+                            />
+                        </div>
+                        <div class=present::preview>
+                            <span class=common::label>"Далее"</span>
+                            <SlideView
+                                slide=Signal::derive(next)
+                                background=Signal::derive(move || presentation.with(|p| p.background))
+                                blank=Signal::derive(|| false)
+                            />
+                        </div>
+                    </div>
+                    <div class=present::controls>
+                        <button class=common::btnSecondary on:click=move |_| step(presentation, slides, -1)>"← Назад"</button>
+                        <button
+                            class=common::btnSecondary
+                            on:click=move |_| presentation.update(|p| p.blank = !p.blank)
+                        >
+                            {move || if presentation.with(|p| p.blank) { "Показать экран" } else { "Скрыть экран" }}
+                        </button>
+                        <button class=common::btnSecondary on:click=move |_| step(presentation, slides, 1)>"Далее →"</button>
+                    </div>
+                    <button class=common::btnPrimary on:click=open_screen>"Открыть экран для зала"</button>
+                    <p class=common::textMuted>
+                        "Перетащите окно на проектор и нажмите F11 или дважды щёлкните по нему. \
+                         Слайды листаются стрелками, PageUp/PageDown и пультом, B — скрыть экран."
+                    </p>
+                </section>
+            </div>
+        </div>
+    }
+}
+
+/// Slide layout and background pickers.
+#[component]
+fn PresentOptions(presentation: RwSignal<Presentation>) -> impl IntoView {
+    view! {
+        <div class=common::tabs>
+            {SlideMode::ALL.into_iter().map(|mode| view! {
+                <button
+                    class=move || if presentation.with(|p| p.mode) == mode {
+                        format!("{} {}", common::tab, common::tabActive)
+                    } else {
+                        common::tab.to_string()
+                    }
+                    on:click=move |_| presentation.update(|p| {
+                        p.mode = mode;
+                        p.index = 0;
+                    })
+                >{mode.label()}</button>
+            }).collect_view()}
+        </div>
+        <div class=present::swatches>
+            {Background::ALL.into_iter().map(|value| view! {
+                <button
+                    class=move || if presentation.with(|p| p.background) == value {
+                        format!("{} {}", present::swatch, present::swatchActive)
+                    } else {
+                        present::swatch.to_string()
+                    }
+                    style:background=value.swatch()
+                    title=value.label()
+                    aria-label=value.label()
+                    on:click=move |_| presentation.update(|p| p.background = value)
+                />
+            }).collect_view()}
+        </div>
+    }
+}
+
+/// Audience screen, opened in a second window.
+#[must_use]
+#[component]
+pub fn PresentScreen() -> impl IntoView {
+    let (presentation, slides) = use_presentation();
+
+    let current = move || {
+        let index = presentation.with(|p| p.index);
+        slides.with(|s| s.get(index).cloned())
+    };
+
+    let fullscreen = move |_| {
+        if let Some(root) = document().document_element() {
+            let _ = root.request_fullscreen();
+        }
+    };
+
+    view! {
+        <div class=present::screen on:dblclick=fullscreen>
+            <SlideView
+                slide=Signal::derive(current)
+                background=Signal::derive(move || presentation.with(|p| p.background))
+                blank=Signal::derive(move || presentation.with(|p| p.blank))
+            />
+        </div>
+    }
+}
+
+/// Slide in large type, scaled to its container.
+#[component]
+fn SlideView(
+    slide: Signal<Option<Slide>>,
+    background: Signal<Background>,
+    blank: Signal<bool>
+) -> impl IntoView {
+    view! {
+        <div
+            class=present::slide
+            style:background=move || if blank.get() { "#000".to_string() } else { background.get().swatch() }
+            style:color=move || background.get().text_color()
+        >
+            {move || slide.get().filter(|_| !blank.get()).map(|slide| {
+                // Shorter slides get larger type
+                let size = match slide.text.chars().count() {
+                    0..=80 => 6.5,
+                    81..=160 => 5.2,
+                    _ => 4.2
+                };
+                view! {
+                    <p class=present::slideText style=format!("font-size: {size}cqw")>{slide.text}</p>
+                    <span
+                        class=present::slideReference
+                        style:color=move || background.get().accent_color()
+                    >
+                        {slide.reference}
+                    </span>
+                }
+            })}
+        </div>
+    }
+}
+
+/// Presentation state synced with the other window, and its slides.
+fn use_presentation() -> (RwSignal<Presentation>, Memo<Vec<Slide>>) {
+    let presentation = RwSignal::new(Presentation::load());
+    Effect::new(move |_| presentation.with(Presentation::save));
+
+    // Follow changes made in the other window
+    let storage = window_event_listener(ev::storage, move |_| {
+        let loaded = Presentation::load();
+        if presentation.with_untracked(|p| *p != loaded) {
+            presentation.set(loaded);
+        }
+    });
+
+    // Keyboards and clickers send arrows, PageUp/PageDown and B or `.`
+    let slides = use_slides(presentation);
+    let keys = window_event_listener(ev::keydown, move |ev| {
+        let editing = ev
+            .target()
+            .and_then(|t| t.dyn_into::<web_sys::HtmlElement>().ok())
+            .is_some_and(|el| el.tag_name() == "INPUT");
+        if editing {
+            return;
+        }
+        match ev.code().as_str() {
+            "ArrowRight" | "ArrowDown" | "PageDown" | "Space" => {
+                ev.prevent_default();
+                step(presentation, slides, 1);
+            }
+            "ArrowLeft" | "ArrowUp" | "PageUp" | "Backspace" => {
+                ev.prevent_default();
+                step(presentation, slides, -1);
+            }
+            "Home" => presentation.update(|p| p.index = 0),
+            "KeyB" | "Period" => presentation.update(|p| p.blank = !p.blank),
+            _ => {}
+        }
+    });
+
+    on_cleanup(move || {
+        storage.remove();
+        keys.remove();
+    });
+    (presentation, slides)
+}
+
+/// Slides of the current passage.
+fn use_slides(presentation: RwSignal<Presentation>) -> Memo<Vec<Slide>> {
+    let state = expect_context::<AppState>();
+    Memo::new(move |_| {
+        let (reference, mode) = presentation.with(|p| (p.reference.clone(), p.mode));
+        let Some(reference) = Reference::parse(&reference) else {
+            return Vec::new();
+        };
+        state
+            .bible
+            .with(|bible| {
+                bible
+                    .as_ref()
+                    .map(|b| slides(&b.get_passage(&reference), mode))
+            })
+            .unwrap_or_default()
+    })
+}
+
+/// Moves by `delta` slides within the passage.
+fn step(presentation: RwSignal<Presentation>, slides: Memo<Vec<Slide>>, delta: isize) {
+    let last = slides.with_untracked(Vec::len).saturating_sub(1);
+    presentation.update(|p| {
+        p.index = p.index.saturating_add_signed(delta).min(last);
+        p.blank = false;
+    });
+}
@@ -0,0 +1,239 @@
+//! Printable passages - layout options and a clean page for print or PDF
+
+use leptos::prelude::*;
+use leptos_router::hooks::use_query_map;
+use revelation_bible::{Pericope, Verse};
+
+use crate::{
+    api,
+    bible::{Translation, book_name},
+    components::Header,
+    haptic,
+    reference::Reference,
+    state::AppState
+};
+
+#[allow(dead_code)]
+mod styles {
+    stylance::import_crate_style!(pub common, "src/styles/common.module.css");
+    stylance::import_crate_style!(pub settings, "src/styles/settings.module.css");
+    stylance::import_crate_style!(pub print, "src/styles/print.module.css");
+}
+use styles::{common, print, settings};
+
+/// Font size range of the printed text, in points.
+const FONT_SIZES: (u8, u8) = (9, 20);
+
+/// Print layout options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PrintOptions {
+    two_columns:   bool,
+    verse_numbers: bool,
+    headings:      bool,
+    font_size:     u8
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self {
+            two_columns:   false,
+            verse_numbers: true,
+            headings:      true,
+            font_size:     12
+        }
+    }
+}
+
+/// Print page; the passage comes from `?ref=` or the input.
+#[must_use]
+#[component]
+pub fn PrintPassage() -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let query = use_query_map();
+    let input = RwSignal::new(query.read_untracked().get("ref").unwrap_or_default());
+    let reference = RwSignal::new(Reference::parse(&input.get_untracked()));
+    let options = RwSignal::new(PrintOptions::default());
+
+    let verses = Memo::new(move |_| {
+        let reference = reference.get()?;
+        state
+            .bible
+            .with(|bible| bible.as_ref().map(|b| b.get_passage(&reference)))
+    });
+
+    let pericopes = LocalResource::new(move || {
+        let book = reference.get().map(|r| r.book_id);
+        async move {
+            match book {
+                Some(book) => api::get_pericopes(book).await.unwrap_or_default(),
+                None => Vec::new()
+            }
+        }
+    });
+
+    let on_apply = move || reference.set(Reference::parse(&input.get_untracked()));
+
+    let on_print = move |_| {
+        haptic::tap();
+        // The title becomes the PDF file name
+        let title = document().title();
+        if let Some(r) = reference.get_untracked() {
+            document().set_title(&r.to_string());
+        }
+        let _ = window().print();
+        document().set_title(&title);
+    };
+
+    view! {
+        <div class=common::page>
+            <Header title="Печать" back=true/>
+
+            <div class=common::container>
+                <section class=common::section>
+                    <div class=print::form>
+                        <input
+                            class=common::input
+                            placeholder="Например, Мф 5:1-7:29"
+                            prop:value=input
+                            on:input=move |ev| input.set(event_target_value(&ev))
+                            on:change=move |_| on_apply()
+                            on:keydown=move |ev| if ev.key() == "Enter" { on_apply(); }
+                        />
+                        <button class=common::btnPrimary on:click=move |_| on_apply()>"Показать"</button>
+                    </div>
+                    <div class=print::options>
+                        <PrintOptionsPanel options=options/>
+                    </div>
+                    <button
+                        class=common::btnPrimary
+                        disabled=move || verses.with(|v| v.as_ref().is_none_or(Vec::is_empty))
+                        on:click=on_print
+                    >
+                        "Печать или PDF"
+                    </button>
+                </section>
+
+                {move || match (reference.get(), verses.get()) {
+                    (Some(reference), Some(verses)) if !verses.is_empty() => view! {
+                        <PrintDocument
+                            reference=reference
+                            verses=verses
+                            pericopes=Signal::derive(move || pericopes.get().unwrap_or_default())
+                            options=options
+                        />
+                    }.into_any(),
+                    (None, _) if !input.with_untracked(String::is_empty) => view! {
+                        <p class=common::textMuted>"Не удалось разобрать ссылку"</p>
+                    }.into_any(),
+                    _ => view! {
+                        <p class=common::textMuted>"Введите ссылку на отрывок"</p>
+                    }.into_any()
+                }}
+            </div>
+        </div>
+    }
+}
+
+/// Layout switches.
+#[component]
+fn PrintOptionsPanel(options: RwSignal<PrintOptions>) -> impl IntoView {
+    let toggle =
+        |label: &'static str, get: fn(&PrintOptions) -> bool, set: fn(&mut PrintOptions)| {
+            view! {
+                <div class=settings::row>
+                    <span class=settings::rowLabel>{label}</span>
+                    <button
+                        class=move || if options.with(get) {
+                            format!("{} {}", settings::toggle, settings::toggleOn)
+                        } else { settings::toggle.to_string() }
+                        on:click=move |_| { haptic::tap(); options.update(set); }
+                    >
+                        <span class=settings::toggleThumb></span>
+                    </button>
+                </div>
+            }
+        };
+
+    view! {
+        <div class=settings::sectionContent>
+            {toggle("Две колонки", |o| o.two_columns, |o| o.two_columns = !o.two_columns)}
+            {toggle("Номера стихов", |o| o.verse_numbers, |o| o.verse_numbers = !o.verse_numbers)}
+            {toggle("Заголовки", |o| o.headings, |o| o.headings = !o.headings)}
+            <div class=settings::row>
+                <span class=settings::rowLabel>"Размер шрифта"</span>
+                <div class=settings::sizeControls>
+                    <button class=settings::sizeBtn on:click=move |_| options.update(|o| {
+                        o.font_size = o.font_size.saturating_sub(1).max(FONT_SIZES.0);
+                    })>"A-"</button>
+                    <span class=settings::sizeValue>{move || format!("{} pt", options.with(|o| o.font_size))}</span>
+                    <button class=settings::sizeBtn on:click=move |_| options.update(|o| {
+                        o.font_size = (o.font_size + 1).min(FONT_SIZES.1);
+                    })>"A+"</button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// The page that gets printed.
+#[component]
+fn PrintDocument(
+    reference: Reference,
+    verses: Vec<Verse>,
+    pericopes: Signal<Vec<Pericope>>,
+    options: RwSignal<PrintOptions>
+) -> impl IntoView {
+    let title = format!(
+        "{} {}",
+        book_name(reference.book_id).unwrap_or_default(),
+        reference
+            .to_string()
+            .split_once(' ')
+            .map_or("", |(_, rest)| rest)
+    );
+    let spans_chapters = reference.end_chapter > reference.chapter;
+    let first_chapter = verses.first().map(|v| v.chapter);
+
+    view! {
+        <article
+            class=print::document
+            style=move || options.with(|o| format!(
+                "font-size: {}pt; column-count: {}",
+                o.font_size,
+                if o.two_columns { 2 } else { 1 }
+            ))
+        >
+            <header class=print::header>
+                <h1 class=print::title>{title}</h1>
+                <span class=print::translation>{Translation::Synodal.name()}</span>
+            </header>
+            <div class=print::text>
+                {verses.into_iter().map(|v| {
+                    let (chapter, verse) = (v.chapter, v.verse);
+                    let heading = move || {
+                        options.with(|o| o.headings).then(|| {
+                            pericopes.with(|p| {
+                                p.iter()
+                                    .find(|p| p.chapter == chapter && p.verse == verse)
+                                    .map(|p| p.heading.clone())
+                            })
+                        }).flatten()
+                    };
+                    let chapter_mark = (spans_chapters && verse == 1 && Some(chapter) != first_chapter)
+                        .then(|| view! { <h2 class=print::chapter>{format!("Глава {chapter}")}</h2> });
+                    view! {
+                        {chapter_mark}
+                        {move || heading().map(|h| view! { <h3 class=print::heading>{h}</h3> })}
+                        <span class=print::verse>
+                            {move || options.with(|o| o.verse_numbers).then(|| view! {
+                                <sup class=print::verseNum>{verse}</sup>
+                            })}
+                            {v.text}
+                            " "
+                        </span>
+                    }
+                }).collect_view()}
+            </div>
+        </article>
+    }
+}
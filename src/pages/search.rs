@@ -1,35 +1,79 @@
 //! Bible search page
 
+use std::collections::{BTreeMap, BTreeSet};
+
 use leptos::prelude::*;
+use revelation_bible::Verse;
 
 use crate::{
     api,
-    components::{Header, Loading, VerseCard}
+    bible::{BibleCache, book_abbreviation, book_name},
+    components::{Header, Loading, VerseCard},
+    query::Query,
+    search::{self, BookRange, SearchFilter, SearchHit},
+    state::AppState
 };
 
 #[allow(dead_code)]
 mod styles {
     stylance::import_crate_style!(pub common, "src/styles/common.module.css");
+    stylance::import_crate_style!(pub search, "src/styles/search.module.css");
 }
-use styles::common;
+use styles::{common, search as search_styles};
+
+/// Results rendered at once; the per-book counts narrow the rest.
+const MAX_SHOWN: usize = 200;
 
 /// Search page
 #[must_use]
 #[component]
 pub fn Search() -> impl IntoView {
+    let bible = expect_context::<AppState>().bible;
     let query = RwSignal::new(String::new());
     let search_type = RwSignal::new(SearchType::FullText);
+    let filter = RwSignal::new(SearchFilter::default());
+
+    // Only full-text search uses the query language
+    let parsed = Memo::new(move |_| {
+        let q = query.get();
+        (search_type.get() == SearchType::FullText && !q.trim().is_empty())
+            .then(|| Query::parse(&q))
+    });
 
+    // Falls back to the downloaded Bible when the server can't be reached
     let results = LocalResource::new(move || {
         let q = query.get();
         let t = search_type.get();
+        let f = filter.get();
+        let parsed = parsed.get();
         async move {
             if q.trim().is_empty() {
                 return None;
             }
             match t {
-                SearchType::FullText => api::search_bible(&q).await.ok(),
-                SearchType::Symphony => api::get_symphony(&q).await.ok().map(|r| r.verses)
+                SearchType::FullText => {
+                    let query = parsed?.ok()?;
+                    let online = api::search_bible_query(&query, &f).await;
+                    online.ok().or_else(|| {
+                        search_downloaded(bible, |b| search::search_offline(b, &query, &f))
+                    })
+                }
+                SearchType::Symphony => {
+                    let word = Query::Word(search::normalize(q.trim()));
+                    let online = api::get_symphony(&q)
+                        .await
+                        .map(|r| r.verses.into_iter().map(|r| r.verse).collect());
+                    online
+                        .ok()
+                        .or_else(|| search_downloaded(bible, |b| search::concordance(b, &q)))
+                        .map(|verses: Vec<Verse>| {
+                            verses
+                                .into_iter()
+                                .filter(|v| f.allows(v.book_id))
+                                .map(|v| SearchHit::new(v, &word))
+                                .collect()
+                        })
+                }
             }
         }
     });
@@ -40,6 +84,10 @@ pub fn Search() -> impl IntoView {
             <div class=common::container>
                 <SearchInput query=query/>
                 <SearchTabs search_type=search_type/>
+                {move || parsed.get().and_then(Result::err).map(|message| view! {
+                    <p class=search_styles::queryError role="alert">{message}</p>
+                })}
+                <SearchFilters filter=filter/>
                 <Suspense fallback=|| view! { <Loading/> }>
                     {move || {
                         let q = query.get();
@@ -58,12 +106,21 @@ pub fn Search() -> impl IntoView {
 #[must_use]
 #[component]
 fn SearchInput(query: RwSignal<String>) -> impl IntoView {
+    let input_ref: NodeRef<leptos::html::Input> = NodeRef::new();
+
+    Effect::new(move |_| {
+        if let Some(input) = input_ref.get() {
+            let _ = input.focus();
+        }
+    });
+
     view! {
         <div class=common::searchWrapper>
             <div class=common::searchIcon><SearchIcon/></div>
             <input
+                node_ref=input_ref
                 type="search"
-                placeholder="Введите слово или фразу..."
+                placeholder="Слово, \"фраза\", вера AND любовь..."
                 class=common::searchInput
                 prop:value=query
                 on:input=move |ev| query.set(event_target_value(&ev))
@@ -101,34 +158,209 @@ fn SearchEmptyState() -> impl IntoView {
             <div class=common::emptyIcon><SearchBigIcon/></div>
             <h2 class=common::emptyTitle>"Поиск по Библии"</h2>
             <p class=common::emptyDesc>"Введите слово или фразу для поиска"</p>
+            <p class=common::emptyDesc>
+                "Фраза в кавычках ищется целиком, AND, OR и NOT объединяют слова, "
+                "а благослов* найдёт все слова с этим началом"
+            </p>
         </div>
     }
 }
 
+/// Searches the downloaded Bible, if there is one.
+fn search_downloaded<T>(
+    bible: RwSignal<Option<BibleCache>>,
+    run: impl FnOnce(&BibleCache) -> T
+) -> Option<T> {
+    bible.with_untracked(|bible| bible.as_ref().map(run))
+}
+
+/// Testament, range and book filters.
 #[must_use]
 #[component]
-fn SearchResults(results: Vec<revelation_bible::SearchResult>) -> impl IntoView {
+fn SearchFilters(filter: RwSignal<SearchFilter>) -> impl IntoView {
+    let open = RwSignal::new(false);
+
+    let chip_class = move |active: bool| {
+        if active {
+            format!("{} {}", search_styles::chip, search_styles::chipActive)
+        } else {
+            search_styles::chip.to_string()
+        }
+    };
+
+    view! {
+        <div class=search_styles::filterBar>
+            <button
+                class=move || chip_class(open.get() || !filter.with(SearchFilter::is_empty))
+                aria-expanded=move || open.get().to_string()
+                on:click=move |_| open.update(|o| *o = !*o)
+            >
+                {move || filter.with(SearchFilter::summary)}
+            </button>
+            <Show when=move || !filter.with(SearchFilter::is_empty)>
+                <button class=search_styles::reset on:click=move |_| filter.set(SearchFilter::default())>
+                    "Сбросить"
+                </button>
+            </Show>
+        </div>
+        <Show when=move || open.get()>
+            <div class=search_styles::filters>
+                <div class=search_styles::chips>
+                    {BookRange::ALL.into_iter().map(|range| view! {
+                        <button
+                            class=move || chip_class(filter.with(|f| f.has_range(range)))
+                            on:click=move |_| filter.update(|f| f.toggle_range(range))
+                        >
+                            {range.label()}
+                        </button>
+                    }).collect_view()}
+                </div>
+                <div class=search_styles::books>
+                    {(1..=66).map(|book_id| view! {
+                        <button
+                            class=move || chip_class(filter.with(|f| f.has_book(book_id)))
+                            title=book_name(book_id).unwrap_or_default()
+                            on:click=move |_| filter.update(|f| f.toggle_book(book_id))
+                        >
+                            {book_abbreviation(book_id).unwrap_or_default()}
+                        </button>
+                    }).collect_view()}
+                </div>
+            </div>
+        </Show>
+    }
+}
+
+#[must_use]
+#[component]
+fn SearchResults(results: Vec<SearchHit>) -> impl IntoView {
     if results.is_empty() {
-        view! {
+        return view! {
             <div class=common::emptyState>
                 <div class=common::emptyIcon><NoResultsIcon/></div>
                 <h2 class=common::emptyTitle>"Ничего не найдено"</h2>
-                <p class=common::emptyDesc>"Попробуйте изменить запрос"</p>
+                <p class=common::emptyDesc>"Попробуйте изменить запрос или фильтры"</p>
             </div>
         }
-        .into_any()
-    } else {
-        view! {
-            <div>
-                <p class=common::resultCount>"Найдено: " {results.len()} " стихов"</p>
-                <div style="display: flex; flex-direction: column; gap: var(--space-xs);">
-                    {results.into_iter().map(|r| view! {
-                        <VerseCard verse=r.verse book_name=r.book_name/>
-                    }).collect::<Vec<_>>()}
-                </div>
+        .into_any();
+    }
+
+    let mut by_book = BTreeMap::<i16, usize>::new();
+    for hit in &results {
+        *by_book.entry(hit.verse.book_id).or_default() += 1;
+    }
+    let total = results.len();
+    let results = StoredValue::new(results);
+    // Books picked from the counts; none picked shows every book
+    let picked = RwSignal::new(BTreeSet::<i16>::new());
+    let collapsed = RwSignal::new(BTreeSet::<i16>::new());
+
+    let shown = move || {
+        picked.with(|picked| {
+            results.with_value(|results| {
+                results
+                    .iter()
+                    .filter(|h| picked.is_empty() || picked.contains(&h.verse.book_id))
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+        })
+    };
+
+    view! {
+        <div>
+            <p class=common::resultCount>"Найдено: " {total} " стихов"</p>
+            <div class=search_styles::counts>
+                {by_book.iter().map(|(&book_id, &count)| view! {
+                    <button
+                        class=move || if picked.with(|p| p.contains(&book_id)) {
+                            format!("{} {}", search_styles::chip, search_styles::chipActive)
+                        } else {
+                            search_styles::chip.to_string()
+                        }
+                        on:click=move |_| picked.update(|p| {
+                            if !p.remove(&book_id) {
+                                p.insert(book_id);
+                            }
+                        })
+                    >
+                        {book_abbreviation(book_id).unwrap_or_default()}
+                        <span class=search_styles::count>{count}</span>
+                    </button>
+                }).collect_view()}
             </div>
+            {move || {
+                let shown = shown();
+                let hidden = shown.len().saturating_sub(MAX_SHOWN);
+                let groups = group_by_book(shown.into_iter().take(MAX_SHOWN));
+                view! {
+                    {groups.into_iter().map(|(book_id, hits)| view! {
+                        <BookGroup
+                            book_id=book_id
+                            total=by_book.get(&book_id).copied().unwrap_or(hits.len())
+                            hits=hits
+                            collapsed=collapsed
+                        />
+                    }).collect_view()}
+                    {(hidden > 0).then(|| view! {
+                        <p class=common::textMuted>
+                            {format!("Показаны первые {MAX_SHOWN}, ещё {hidden} — выберите книги выше")}
+                        </p>
+                    })}
+                }
+            }}
+        </div>
+    }
+    .into_any()
+}
+
+/// Splits hits in canonical order into runs of the same book.
+fn group_by_book(hits: impl Iterator<Item = SearchHit>) -> Vec<(i16, Vec<SearchHit>)> {
+    let mut groups: Vec<(i16, Vec<SearchHit>)> = Vec::new();
+    for hit in hits {
+        match groups.last_mut() {
+            Some((book_id, items)) if *book_id == hit.verse.book_id => items.push(hit),
+            _ => groups.push((hit.verse.book_id, vec![hit]))
         }
-        .into_any()
+    }
+    groups
+}
+
+/// Collapsible section with the hits in one book.
+#[must_use]
+#[component]
+fn BookGroup(
+    book_id: i16,
+    /// Hits in the book before truncation.
+    total: usize,
+    hits: Vec<SearchHit>,
+    collapsed: RwSignal<BTreeSet<i16>>
+) -> impl IntoView {
+    let is_open = move || !collapsed.with(|c| c.contains(&book_id));
+    let name = book_name(book_id).unwrap_or_default();
+
+    view! {
+        <section class=search_styles::group>
+            <button
+                class=search_styles::groupHead
+                aria-expanded=move || is_open().to_string()
+                on:click=move |_| collapsed.update(|c| {
+                    if !c.remove(&book_id) {
+                        c.insert(book_id);
+                    }
+                })
+            >
+                <span class=search_styles::groupTitle>{name}</span>
+                <span class=search_styles::count>{total}</span>
+            </button>
+            <Show when=is_open>
+                <div class=search_styles::results>
+                    {hits.clone().into_iter().map(|hit| view! {
+                        <VerseCard verse=hit.verse book_name=name.to_string() highlights=hit.spans/>
+                    }).collect_view()}
+                </div>
+            </Show>
+        </section>
     }
 }
 
@@ -4,7 +4,10 @@ use leptos::prelude::*;
 use revelation_ui::theme::{FontFamily, Theme, use_theme};
 use wasm_bindgen::prelude::*;
 
-use crate::{components::Header, haptic};
+use crate::{
+    audio::AudioDownloads, commentary::CommentaryProvider, components::Header, haptic,
+    share::CopyStyle, state::AppState
+};
 
 stylance::import_crate_style!(styles, "src/styles/settings.module.css");
 
@@ -49,6 +52,7 @@ pub fn Settings() -> impl IntoView {
                 <ReadingSection ts=ts/>
                 <ThemeSection theme=ts.theme/>
                 <AppSection pwa_installable=pwa_installable set_pwa_installable=set_pwa_installable/>
+                <OfflineSection/>
                 <AboutSection/>
             </div>
         </div>
@@ -69,6 +73,8 @@ fn ReadingSection(ts: revelation_ui::theme::ThemeState) -> impl IntoView {
                 <FontSizeRow font_size=font_size/>
                 <FontFamilyRow font_family=font_family/>
                 <VersePerLineRow verse_per_line=verse_per_line/>
+                <LinkNamesRow/>
+                <CopyStyleRow/>
             </div>
         </div>
     }
@@ -141,6 +147,46 @@ fn VersePerLineRow(verse_per_line: RwSignal<bool>) -> impl IntoView {
     }
 }
 
+#[must_use]
+#[component]
+fn LinkNamesRow() -> impl IntoView {
+    let link_names = expect_context::<AppState>().link_names;
+
+    view! {
+        <div class=styles::row>
+            <span class=styles::rowLabel>"Имена и места из словаря"</span>
+            <button
+                class=move || if link_names.get() {
+                    format!("{} {}", styles::toggle, styles::toggleOn)
+                } else { styles::toggle.to_string() }
+                on:click=move |_| { haptic::tap(); link_names.update(|v| *v = !*v); }
+            ><span class=styles::toggleThumb/></button>
+        </div>
+    }
+}
+
+#[must_use]
+#[component]
+fn CopyStyleRow() -> impl IntoView {
+    let copy_style = expect_context::<AppState>().copy_style;
+
+    view! {
+        <div class=styles::row>
+            <span class=styles::rowLabel>"Формат цитаты"</span>
+            <div class=styles::fontBtns>
+                {CopyStyle::ALL.into_iter().map(|style| view! {
+                    <button
+                        class=move || if copy_style.get() == style {
+                            format!("{} {}", styles::fontBtn, styles::fontBtnActive)
+                        } else { styles::fontBtn.to_string() }
+                        on:click=move |_| { haptic::tap(); copy_style.set(style); }
+                    >{style.label()}</button>
+                }).collect::<Vec<_>>()}
+            </div>
+        </div>
+    }
+}
+
 #[must_use]
 #[component]
 fn ThemeSection(theme: RwSignal<Theme>) -> impl IntoView {
@@ -196,6 +242,83 @@ fn AppSection(
     }
 }
 
+#[must_use]
+#[component]
+fn OfflineSection() -> impl IntoView {
+    let (audio_chapters, set_audio_chapters) = signal(AudioDownloads::list().len());
+    let commentary_saved = RwSignal::new(CommentaryProvider::is_downloaded());
+    // Books saved while the commentary is downloading
+    let commentary_progress = RwSignal::new(None::<i16>);
+    let commentary_error = RwSignal::new(None::<String>);
+
+    let download_commentary = move |_| {
+        haptic::tap();
+        commentary_error.set(None);
+        commentary_progress.set(Some(0));
+        wasm_bindgen_futures::spawn_local(async move {
+            let result =
+                CommentaryProvider::download(|books| commentary_progress.set(Some(books))).await;
+            commentary_progress.set(None);
+            commentary_saved.set(result.is_ok());
+            commentary_error.set(result.err());
+        });
+    };
+
+    let remove_commentary = move |_| {
+        haptic::tap();
+        wasm_bindgen_futures::spawn_local(async move {
+            if CommentaryProvider::remove().await.is_ok() {
+                commentary_saved.set(false);
+            }
+        });
+    };
+
+    let clear_audio = move |_| {
+        haptic::tap();
+        wasm_bindgen_futures::spawn_local(async move {
+            if AudioDownloads::clear().await.is_ok() {
+                set_audio_chapters.set(0);
+            }
+        });
+    };
+
+    view! {
+        <div class=styles::section>
+            <h3 class=styles::sectionTitle>"Офлайн"</h3>
+            <div class=styles::sectionContent>
+                <div class=styles::row>
+                    <span class=styles::rowLabel>"Аудиозаписи"</span>
+                    <span class=styles::rowValue>
+                        {move || format!("{} гл.", audio_chapters.get())}
+                    </span>
+                    <Show when=move || { audio_chapters.get() > 0 }>
+                        <button class=styles::fontBtn on:click=clear_audio>"Удалить"</button>
+                    </Show>
+                </div>
+                <div class=styles::row>
+                    <span class=styles::rowLabel>"Толкование Лопухина"</span>
+                    {move || match (commentary_progress.get(), commentary_saved.get()) {
+                        (Some(books), _) => view! {
+                            <span class=styles::rowValue>{format!("{books} из 66")}</span>
+                        }.into_any(),
+                        (None, true) => view! {
+                            <button class=styles::fontBtn on:click=remove_commentary>"Удалить"</button>
+                        }.into_any(),
+                        (None, false) => view! {
+                            <button class=styles::fontBtn on:click=download_commentary>
+                                {move || if commentary_error.with(Option::is_some) { "Повторить" } else { "Скачать" }}
+                            </button>
+                        }.into_any()
+                    }}
+                </div>
+                {move || commentary_error.get().map(|error| view! {
+                    <p class=styles::error>{format!("Не удалось скачать: {error}")}</p>
+                })}
+            </div>
+        </div>
+    }
+}
+
 #[must_use]
 #[component]
 fn PwaStatus(
@@ -0,0 +1,118 @@
+//! Reading statistics - streaks, time and Bible coverage
+
+use leptos::prelude::*;
+use revelation_bible::Book;
+
+use crate::{
+    bible::{BibleCache, book_abbreviation, get_book_category_var},
+    components::{BottomNav, Header, Loading},
+    daily,
+    state::AppState,
+    stats::ReadingHistory
+};
+
+#[allow(dead_code)]
+mod styles {
+    stylance::import_crate_style!(pub common, "src/styles/common.module.css");
+    stylance::import_crate_style!(pub stats, "src/styles/stats.module.css");
+}
+use styles::{common, stats};
+
+#[must_use]
+#[component]
+pub fn Stats() -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let history = StoredValue::new(ReadingHistory::load());
+    let books = Memo::new(move |_| state.bible.with(|b| b.as_ref().map(BibleCache::get_books)));
+
+    let total_chapters = move || {
+        books.with(|b| {
+            b.as_ref().map_or(0, |b| {
+                b.iter()
+                    .map(|b| usize::try_from(b.chapters_count).unwrap_or(0))
+                    .sum()
+            })
+        })
+    };
+
+    let (read, streak, longest, minutes) = history.with_value(|h| {
+        (
+            h.chapters.len(),
+            h.current_streak(daily::today()),
+            h.longest_streak(),
+            h.total_minutes()
+        )
+    });
+
+    view! {
+        <div class=common::page>
+            <Header title="Статистика" back=true/>
+
+            <div class=common::container>
+                <div class=stats::summary>
+                    <Tile value=streak.to_string() label="Дней подряд"/>
+                    <Tile value=longest.to_string() label="Лучшая серия"/>
+                    <Tile value=minutes.to_string() label="Минут чтения"/>
+                    <Tile
+                        value=Signal::derive(move || format!("{read} / {}", total_chapters()))
+                        label="Глав прочитано"
+                    />
+                </div>
+
+                <section class=common::section>
+                    <h2 class=common::sectionTitle>"Покрытие Библии"</h2>
+                    {move || books.get().map_or_else(
+                        || view! { <Loading/> }.into_any(),
+                        |books| view! {
+                            <div class=stats::heatmap>
+                                {books.into_iter().map(|book| view! {
+                                    <BookRow book=book history=history/>
+                                }).collect_view()}
+                            </div>
+                        }.into_any()
+                    )}
+                </section>
+            </div>
+
+            <BottomNav/>
+        </div>
+    }
+}
+
+/// Single summary figure.
+#[component]
+fn Tile(#[prop(into)] value: Signal<String>, label: &'static str) -> impl IntoView {
+    view! {
+        <div class=stats::tile>
+            <span class=stats::tileValue>{move || value.get()}</span>
+            <span class=stats::tileLabel>{label}</span>
+        </div>
+    }
+}
+
+/// One book: read count and a cell per chapter.
+#[component]
+fn BookRow(book: Book, history: StoredValue<ReadingHistory>) -> impl IntoView {
+    let color = get_book_category_var(book.id);
+    let read = history.with_value(|h| h.read_in_book(book.id));
+
+    view! {
+        <div class=stats::book>
+            <span class=stats::bookName>{book_abbreviation(book.id).unwrap_or_default()}</span>
+            <div class=stats::cells>
+                {(1..=book.chapters_count).map(|chapter| {
+                    let is_read = history.with_value(|h| h.is_read(book.id, chapter));
+                    view! {
+                        <a
+                            href=format!("/bible/{}/{chapter}", book.id)
+                            class=stats::cell
+                            title=format!("{} {chapter}", book.name_ru)
+                            style=is_read.then(|| format!("background: {color}"))
+                        />
+                    }
+                }).collect_view()}
+            </div>
+            <span class=stats::bookCount>{format!("{read}/{}", book.chapters_count)}</span>
+        </div>
+    }
+}
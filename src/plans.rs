@@ -0,0 +1,215 @@
+//! Reading plans - schedules built from the cached Bible, progress and
+//! catch-up.
+
+use std::{collections::BTreeSet, ops::RangeInclusive};
+
+use chrono::{Days, NaiveDate};
+use gloo_storage::{LocalStorage, Storage};
+use revelation_bible::Book;
+use serde::{Deserialize, Serialize};
+
+use crate::reference::Reference;
+
+const PLANS_KEY: &str = "reading_plans";
+
+/// Built-in reading plan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PlanKind {
+    /// Whole Bible in a year.
+    BibleYear,
+    /// New Testament in 90 days.
+    NewTestament90,
+    /// Psalms every month.
+    PsalmsMonth
+}
+
+impl PlanKind {
+    /// All plans in display order.
+    pub const ALL: [Self; 3] = [Self::BibleYear, Self::NewTestament90, Self::PsalmsMonth];
+
+    /// URL slug.
+    #[must_use]
+    pub const fn slug(self) -> &'static str {
+        match self {
+            Self::BibleYear => "bible-year",
+            Self::NewTestament90 => "nt-90",
+            Self::PsalmsMonth => "psalms-month"
+        }
+    }
+
+    /// Finds a plan by its URL slug.
+    #[must_use]
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.slug() == slug)
+    }
+
+    /// Plan title.
+    #[must_use]
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::BibleYear => "Библия за год",
+            Self::NewTestament90 => "Новый Завет за 90 дней",
+            Self::PsalmsMonth => "Псалтирь за месяц"
+        }
+    }
+
+    /// Short description.
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::BibleYear => "Все 66 книг по порядку, 3-4 главы в день",
+            Self::NewTestament90 => "От Матфея до Откровения, около 3 глав в день",
+            Self::PsalmsMonth => "150 псалмов за 30 дней, по 5 в день"
+        }
+    }
+
+    /// Number of days in the plan.
+    #[must_use]
+    pub const fn days(self) -> u16 {
+        match self {
+            Self::BibleYear => 365,
+            Self::NewTestament90 => 90,
+            Self::PsalmsMonth => 30
+        }
+    }
+
+    /// Books covered by the plan.
+    const fn books(self) -> RangeInclusive<i16> {
+        match self {
+            Self::BibleYear => 1..=66,
+            Self::NewTestament90 => 40..=66,
+            Self::PsalmsMonth => 19..=19
+        }
+    }
+
+    /// Builds the day-by-day schedule from the books' chapter counts.
+    ///
+    /// Chapters are spread evenly over the days; consecutive chapters of one
+    /// book are merged into a single reference.
+    #[must_use]
+    pub fn schedule(self, books: &[Book]) -> Vec<Vec<Reference>> {
+        let range = self.books();
+        let chapters: Vec<(i16, i16)> = books
+            .iter()
+            .filter(|book| range.contains(&book.id))
+            .flat_map(|book| (1..=book.chapters_count).map(move |c| (book.id, c)))
+            .collect();
+
+        let days = usize::from(self.days());
+        let total = chapters.len();
+        (0..days)
+            .map(|day| group_chapters(&chapters[day * total / days..(day + 1) * total / days]))
+            .collect()
+    }
+}
+
+/// Merges consecutive chapters of the same book into references.
+fn group_chapters(chapters: &[(i16, i16)]) -> Vec<Reference> {
+    let mut refs: Vec<Reference> = Vec::new();
+    for &(book_id, chapter) in chapters {
+        match refs.last_mut() {
+            Some(last) if last.book_id == book_id && last.end_chapter + 1 == chapter => {
+                last.end_chapter = chapter;
+            }
+            _ => refs.push(Reference::chapter(book_id, chapter))
+        }
+    }
+    refs
+}
+
+/// Progress through a started plan.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanProgress {
+    /// Plan being followed.
+    pub kind:      PlanKind,
+    /// Date of day 1.
+    pub start:     NaiveDate,
+    /// Completed day indices (0-based).
+    pub completed: BTreeSet<u16>
+}
+
+impl PlanProgress {
+    /// Starts a plan on the given date.
+    #[must_use]
+    pub const fn new(kind: PlanKind, start: NaiveDate) -> Self {
+        Self {
+            kind,
+            start,
+            completed: BTreeSet::new()
+        }
+    }
+
+    /// Index of the day scheduled for `today`, clamped to the plan length.
+    #[must_use]
+    pub fn day_for(&self, today: NaiveDate) -> u16 {
+        let elapsed = (today - self.start).num_days().max(0);
+        u16::try_from(elapsed)
+            .unwrap_or(u16::MAX)
+            .min(self.kind.days() - 1)
+    }
+
+    /// Days before `today` that are not completed yet.
+    #[must_use]
+    pub fn missed(&self, today: NaiveDate) -> Vec<u16> {
+        if today < self.start {
+            return Vec::new();
+        }
+        (0..self.day_for(today))
+            .filter(|day| !self.completed.contains(day))
+            .collect()
+    }
+
+    /// Calendar date of a plan day.
+    #[must_use]
+    pub fn date_of(&self, day: u16) -> NaiveDate {
+        self.start
+            .checked_add_days(Days::new(u64::from(day)))
+            .unwrap_or(self.start)
+    }
+
+    /// Marks a day as read or unread.
+    pub fn toggle(&mut self, day: u16) {
+        if !self.completed.remove(&day) {
+            self.completed.insert(day);
+        }
+    }
+
+    /// Moves the schedule so the first unread day falls on `today`.
+    pub fn catch_up(&mut self, today: NaiveDate) {
+        let first_unread = (0..self.kind.days())
+            .find(|day| !self.completed.contains(day))
+            .unwrap_or(0);
+        self.start = today
+            .checked_sub_days(Days::new(u64::from(first_unread)))
+            .unwrap_or(today);
+    }
+
+    /// Completion in percent.
+    #[must_use]
+    pub fn percent(&self) -> u8 {
+        let done = self.completed.len() * 100 / usize::from(self.kind.days());
+        u8::try_from(done).unwrap_or(100)
+    }
+
+    /// Checks whether every day is read.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.completed.len() >= usize::from(self.kind.days())
+    }
+}
+
+/// Reading plan storage.
+pub struct Plans;
+
+impl Plans {
+    /// Loads started plans from `LocalStorage`.
+    #[must_use]
+    pub fn load() -> Vec<PlanProgress> {
+        LocalStorage::get(PLANS_KEY).unwrap_or_default()
+    }
+
+    /// Saves started plans to `LocalStorage`.
+    pub fn save(plans: &[PlanProgress]) {
+        let _ = LocalStorage::set(PLANS_KEY, plans);
+    }
+}
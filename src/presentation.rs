@@ -0,0 +1,166 @@
+//! Projector presentation - slides from a passage, shared between the
+//! control window and the audience screen.
+
+use gloo_storage::{LocalStorage, Storage};
+use revelation_bible::Verse;
+use serde::{Deserialize, Serialize};
+
+use crate::{share::citation, verse_image::Background};
+
+const PRESENTATION_KEY: &str = "presentation";
+
+/// Longest slide text in characters before it is split.
+pub const MAX_SLIDE_CHARS: usize = 240;
+
+/// How verses are laid out on slides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SlideMode {
+    /// One verse per slide.
+    #[default]
+    Verse,
+    /// As many verses as fit on a slide.
+    Passage
+}
+
+impl SlideMode {
+    /// All modes in display order.
+    pub const ALL: [Self; 2] = [Self::Verse, Self::Passage];
+
+    /// Display label.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Verse => "По стиху",
+            Self::Passage => "Отрывком"
+        }
+    }
+}
+
+/// Presentation state, synced to the audience window through
+/// `LocalStorage`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Presentation {
+    /// Reference of the passage being shown.
+    pub reference:  String,
+    /// Slide layout.
+    pub mode:       SlideMode,
+    /// Slide background.
+    pub background: Background,
+    /// Current slide.
+    pub index:      usize,
+    /// Audience screen blanked.
+    pub blank:      bool
+}
+
+impl Presentation {
+    /// Loads the state from `LocalStorage`.
+    #[must_use]
+    pub fn load() -> Self {
+        LocalStorage::get(PRESENTATION_KEY).unwrap_or_default()
+    }
+
+    /// Saves the state to `LocalStorage`, notifying other windows.
+    pub fn save(&self) {
+        let _ = LocalStorage::set(PRESENTATION_KEY, self);
+    }
+}
+
+/// Slide content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Slide {
+    /// Reference such as `Ин 3:16`.
+    pub reference: String,
+    /// Slide text.
+    pub text:      String
+}
+
+/// Lays out verses on slides, splitting long verses.
+#[must_use]
+pub fn slides(verses: &[Verse], mode: SlideMode) -> Vec<Slide> {
+    let mut slides = Vec::new();
+    let mut group: Vec<Verse> = Vec::new();
+    let mut group_len = 0;
+
+    let flush = |group: &mut Vec<Verse>, slides: &mut Vec<Slide>| {
+        let Some(reference) = citation(group) else {
+            return;
+        };
+        let text = group
+            .iter()
+            .map(|v| v.text.trim())
+            .collect::<Vec<_>>()
+            .join(" ");
+        slides.extend(
+            split_text(&text, MAX_SLIDE_CHARS)
+                .into_iter()
+                .map(|text| Slide {
+                    reference: reference.clone(),
+                    text
+                })
+        );
+        group.clear();
+    };
+
+    for verse in verses {
+        let len = verse.text.chars().count();
+        let fits = group_len + len <= MAX_SLIDE_CHARS;
+        if !group.is_empty() && (mode == SlideMode::Verse || !fits) {
+            flush(&mut group, &mut slides);
+            group_len = 0;
+        }
+        group.push(verse.clone());
+        group_len += len + 1;
+    }
+    flush(&mut group, &mut slides);
+    slides
+}
+
+/// Splits text into evenly sized parts of at most `max` characters.
+///
+/// Breaks after punctuation where possible, otherwise between words.
+fn split_text(text: &str, max: usize) -> Vec<String> {
+    let text = text.trim();
+    let total = text.chars().count();
+    if total <= max {
+        return vec![text.to_string()];
+    }
+
+    let mut clauses = Vec::new();
+    let mut start = 0;
+    for (idx, c) in text.char_indices() {
+        if matches!(c, '.' | ',' | ';' | ':' | '!' | '?') {
+            let end = idx + c.len_utf8();
+            clauses.push(&text[start..end]);
+            start = end;
+        }
+    }
+    clauses.push(&text[start..]);
+
+    let pieces = clauses.into_iter().flat_map(|clause| {
+        if clause.chars().count() > max {
+            clause.split_inclusive(' ').collect()
+        } else {
+            vec![clause]
+        }
+    });
+
+    // Aim for equal parts so the last slide is not a short tail
+    let target = total.div_ceil(total.div_ceil(max));
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+    for piece in pieces {
+        let len = piece.chars().count();
+        if current_len > 0 && (current_len >= target || current_len + len > max) {
+            parts.push(current.trim().to_string());
+            current.clear();
+            current_len = 0;
+        }
+        current.push_str(piece);
+        current_len += len;
+    }
+    if !current.trim().is_empty() {
+        parts.push(current.trim().to_string());
+    }
+    parts
+}
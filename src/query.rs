@@ -0,0 +1,548 @@
+//! Search query language: `"exact phrase"`, `любовь AND вера`, `OR`, `NOT`,
+//! parentheses and prefix wildcards like `благослов*`.
+//!
+//! Input without any of this syntax stays a plain text search, as before.
+//! With syntax, words separated by spaces must all match, as if joined with
+//! `AND`; bare words are still matched like plain text, while quoted
+//! phrases and wildcards match whole word forms. Case and `ё` are ignored.
+
+use std::ops::Range;
+
+use crate::search::{find_matches, normalize, word_ranges, words};
+
+/// Shortest stem accepted before `*`.
+const MIN_PREFIX: usize = 2;
+
+/// Parsed search query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Query {
+    /// Plain text, matched like the search without syntax: by the server
+    /// online, as a fragment of the verse offline.
+    Text(String),
+    /// Exact word form.
+    Word(String),
+    /// Any word starting with the stem.
+    Prefix(String),
+    /// Consecutive words.
+    Phrase(Vec<String>),
+    /// Every part matches.
+    And(Vec<Self>),
+    /// At least one part matches.
+    Or(Vec<Self>),
+    /// The inner query doesn't match.
+    Not(Box<Self>)
+}
+
+impl Query {
+    /// Parses user input.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message describing the first syntax error.
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let tokens = tokenize(input)?;
+        if tokens.is_empty() {
+            return Err("Введите слово или фразу".to_string());
+        }
+        let plain = tokens
+            .iter()
+            .all(|t| matches!(t, Token::Word(word) if !word.contains('*')));
+        if plain {
+            return Ok(Self::Text(input.trim().to_string()));
+        }
+        let mut parser = Parser {
+            tokens,
+            pos: 0
+        };
+        let query = parser.or()?;
+        if parser.pos < parser.tokens.len() {
+            return Err("Лишняя закрывающая скобка".to_string());
+        }
+        if !query.has_positive() {
+            return Err("Кроме NOT нужно хотя бы одно искомое слово".to_string());
+        }
+        Ok(query)
+    }
+
+    /// Checks whether verse text matches the query, with plain text parts
+    /// matched as fragments.
+    #[must_use]
+    pub fn matches(&self, text: &str) -> bool {
+        let normalized = normalize(text);
+        self.matches_with(text, |fragment| normalized.contains(&normalize(fragment)))
+    }
+
+    /// Checks whether verse text matches the query, asking `found` whether
+    /// the verse matched each plain text part.
+    ///
+    /// Only phrases, exact words and wildcards are checked against the text
+    /// itself.
+    pub fn matches_with(&self, text: &str, found: impl Fn(&str) -> bool) -> bool {
+        let words: Vec<String> = words(text).map(normalize).collect();
+        self.eval(&words, &found)
+    }
+
+    fn eval(&self, words: &[String], found: &dyn Fn(&str) -> bool) -> bool {
+        match self {
+            Self::Text(fragment) => found(fragment),
+            Self::Word(word) => words.contains(word),
+            Self::Prefix(stem) => words.iter().any(|w| w.starts_with(stem.as_str())),
+            Self::Phrase(phrase) => words.windows(phrase.len()).any(|w| w == phrase.as_slice()),
+            Self::And(items) => items.iter().all(|q| q.eval(words, found)),
+            Self::Or(items) => items.iter().any(|q| q.eval(words, found)),
+            Self::Not(inner) => !inner.eval(words, found)
+        }
+    }
+
+    /// Plain text parts, including negated ones.
+    #[must_use]
+    pub fn texts(&self) -> Vec<&str> {
+        match self {
+            Self::Text(fragment) => vec![fragment.as_str()],
+            Self::Word(_) | Self::Prefix(_) | Self::Phrase(_) => Vec::new(),
+            Self::And(items) | Self::Or(items) => items.iter().flat_map(Self::texts).collect(),
+            Self::Not(inner) => inner.texts()
+        }
+    }
+
+    /// Byte ranges of the text the query looks for in a verse.
+    ///
+    /// Negated parts are skipped; hits separated only by spaces are merged,
+    /// so phrases light up as one span.
+    #[must_use]
+    pub fn spans(&self, text: &str) -> Vec<Range<usize>> {
+        let ranges: Vec<Range<usize>> = word_ranges(text).collect();
+        let words: Vec<String> = ranges.iter().map(|r| normalize(&text[r.clone()])).collect();
+        let mut hits = vec![false; words.len()];
+        self.mark(&words, &mut hits);
+
+        let mut found: Vec<Range<usize>> = ranges
+            .into_iter()
+            .zip(hits)
+            .filter_map(|(range, hit)| hit.then_some(range))
+            .collect();
+        found.extend(
+            self.positive_texts()
+                .into_iter()
+                .flat_map(|fragment| find_matches(text, fragment))
+        );
+        found.sort_by_key(|r| r.start);
+
+        let mut spans: Vec<Range<usize>> = Vec::new();
+        for range in found {
+            match spans.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                Some(last) if text[last.end..range.start].trim().is_empty() => {
+                    last.end = range.end;
+                }
+                _ => spans.push(range)
+            }
+        }
+        spans
+    }
+
+    /// Plain text parts outside negations.
+    fn positive_texts(&self) -> Vec<&str> {
+        match self {
+            Self::Text(fragment) => vec![fragment.as_str()],
+            Self::And(items) | Self::Or(items) => {
+                items.iter().flat_map(Self::positive_texts).collect()
+            }
+            _ => Vec::new()
+        }
+    }
+
+    /// Flags the words matched by the positive word parts of the query.
+    fn mark(&self, words: &[String], hits: &mut [bool]) {
+        match self {
+            Self::Word(word) => {
+                for (w, hit) in words.iter().zip(hits.iter_mut()) {
+                    *hit |= w == word;
+                }
+            }
+            Self::Prefix(stem) => {
+                for (w, hit) in words.iter().zip(hits.iter_mut()) {
+                    *hit |= w.starts_with(stem.as_str());
+                }
+            }
+            Self::Phrase(phrase) => {
+                for start in 0..=words.len().saturating_sub(phrase.len()) {
+                    let end = start + phrase.len();
+                    if words.get(start..end) == Some(phrase.as_slice()) {
+                        hits[start..end].fill(true);
+                    }
+                }
+            }
+            Self::And(items) | Self::Or(items) => {
+                for item in items {
+                    item.mark(words, hits);
+                }
+            }
+            Self::Text(_) | Self::Not(_) => {}
+        }
+    }
+
+    /// Plain-text searches whose combined results contain every match.
+    ///
+    /// This is how the query is sent to the server, which only understands
+    /// plain text: one request per anchor, then [`Query::matches_with`] on
+    /// the hits.
+    #[must_use]
+    pub fn anchors(&self) -> Vec<String> {
+        self.cover().unwrap_or_default()
+    }
+
+    /// Smallest set of literals one of which every match contains, or
+    /// `None` for negations.
+    fn cover(&self) -> Option<Vec<String>> {
+        match self {
+            Self::Text(word) | Self::Word(word) | Self::Prefix(word) => Some(vec![word.clone()]),
+            Self::Phrase(phrase) => Some(vec![phrase.join(" ")]),
+            Self::Not(_) => None,
+            // Fewest requests, then the longest (most selective) literal
+            Self::And(items) => items.iter().filter_map(Self::cover).min_by_key(|cover| {
+                let longest = cover.iter().map(String::len).max().unwrap_or(0);
+                (cover.len(), usize::MAX - longest)
+            }),
+            Self::Or(items) => items
+                .iter()
+                .map(Self::cover)
+                .collect::<Option<Vec<_>>>()
+                .map(|covers| covers.concat())
+        }
+    }
+
+    /// Checks whether the query can match without relying on negation.
+    fn has_positive(&self) -> bool {
+        match self {
+            Self::Text(_) | Self::Word(_) | Self::Prefix(_) | Self::Phrase(_) => true,
+            Self::Not(_) => false,
+            Self::And(items) => items.iter().any(Self::has_positive),
+            Self::Or(items) => items.iter().all(Self::has_positive)
+        }
+    }
+}
+
+/// Lexical unit of a query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Open,
+    Close,
+    And,
+    Or,
+    Not,
+    Phrase(String),
+    Word(String)
+}
+
+impl Token {
+    /// Checks whether the token can start an operand.
+    const fn starts_operand(&self) -> bool {
+        matches!(
+            self,
+            Self::Open | Self::Not | Self::Phrase(_) | Self::Word(_)
+        )
+    }
+}
+
+const fn is_quote(c: char) -> bool {
+    matches!(c, '"' | '«' | '»' | '„' | '“' | '”')
+}
+
+/// Splits input into tokens, dropping bare punctuation.
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '(' => tokens.push(Token::Open),
+            ')' => tokens.push(Token::Close),
+            c if c.is_whitespace() => {}
+            c if is_quote(c) => {
+                let mut phrase = String::new();
+                let mut closed = false;
+                for c in chars.by_ref() {
+                    if is_quote(c) {
+                        closed = true;
+                        break;
+                    }
+                    phrase.push(c);
+                }
+                if !closed {
+                    return Err("Не закрыта кавычка".to_string());
+                }
+                tokens.push(Token::Phrase(phrase));
+            }
+            c => {
+                let mut word = String::from(c);
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() || matches!(next, '(' | ')') || is_quote(next) {
+                        break;
+                    }
+                    word.push(next);
+                    chars.next();
+                }
+                match word.as_str() {
+                    "AND" => tokens.push(Token::And),
+                    "OR" => tokens.push(Token::Or),
+                    "NOT" => tokens.push(Token::Not),
+                    _ if word.chars().any(|c| c.is_alphanumeric() || c == '*') => {
+                        tokens.push(Token::Word(word));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Turns a word token into plain text or, with `*`, a prefix.
+fn word_query(token: &str) -> Result<Query, String> {
+    let (stem, wildcard) = token
+        .strip_suffix('*')
+        .map_or((token, false), |stem| (stem, true));
+    if stem.contains('*') {
+        return Err("Звёздочка ставится только в конце слова".to_string());
+    }
+
+    if !wildcard {
+        let text = token.trim_matches(|c: char| !c.is_alphanumeric());
+        return Ok(Query::Text(text.to_string()));
+    }
+
+    let mut parts: Vec<String> = words(stem).map(normalize).collect();
+    match parts.len() {
+        0 => Err("Звёздочка ставится в конце слова, например благослов*".to_string()),
+        1 if parts[0].chars().count() < MIN_PREFIX => {
+            Err(format!("Перед * нужно хотя бы {MIN_PREFIX} буквы"))
+        }
+        1 => Ok(Query::Prefix(parts.remove(0))),
+        _ => Err(format!("Звёздочка относится к одному слову: {token}"))
+    }
+}
+
+/// Recursive descent over tokens: `OR` binds looser than `AND`, `NOT`
+/// binds tightest.
+struct Parser {
+    tokens: Vec<Token>,
+    pos:    usize
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn or(&mut self) -> Result<Query, String> {
+        let mut items = vec![self.and()?];
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            self.expect_operand("OR")?;
+            items.push(self.and()?);
+        }
+        Ok(collapse(items, Query::Or))
+    }
+
+    fn and(&mut self) -> Result<Query, String> {
+        let mut items = vec![self.unary()?];
+        loop {
+            match self.peek() {
+                Some(Token::And) => {
+                    self.pos += 1;
+                    self.expect_operand("AND")?;
+                }
+                Some(token) if token.starts_operand() => {}
+                _ => break
+            }
+            items.push(self.unary()?);
+        }
+        Ok(collapse(items, Query::And))
+    }
+
+    fn unary(&mut self) -> Result<Query, String> {
+        match self.next() {
+            Some(Token::Not) => {
+                self.expect_operand("NOT")?;
+                Ok(Query::Not(Box::new(self.unary()?)))
+            }
+            Some(Token::Open) => {
+                let inner = self.or()?;
+                match self.next() {
+                    Some(Token::Close) => Ok(inner),
+                    _ => Err("Не закрыта скобка".to_string())
+                }
+            }
+            Some(Token::Phrase(text)) => {
+                let mut phrase: Vec<String> = words(&text).map(normalize).collect();
+                match phrase.len() {
+                    0 => Err("Пустые кавычки".to_string()),
+                    1 => Ok(Query::Word(phrase.remove(0))),
+                    _ => Ok(Query::Phrase(phrase))
+                }
+            }
+            Some(Token::Word(word)) => word_query(&word),
+            Some(Token::Close) if self.pos >= 2 && self.tokens[self.pos - 2] == Token::Open => {
+                Err("Пустые скобки".to_string())
+            }
+            Some(Token::Close) => Err("Лишняя закрывающая скобка".to_string()),
+            Some(Token::And) => Err("Перед AND нужно слово".to_string()),
+            Some(Token::Or) => Err("Перед OR нужно слово".to_string()),
+            None => Err("Не закрыта скобка".to_string())
+        }
+    }
+
+    /// Fails unless an operand follows the operator `op`.
+    fn expect_operand(&self, op: &str) -> Result<(), String> {
+        if self.peek().is_some_and(Token::starts_operand) {
+            Ok(())
+        } else {
+            Err(format!("После {op} нужно слово"))
+        }
+    }
+}
+
+/// Unwraps single-item groups.
+fn collapse(mut items: Vec<Query>, group: fn(Vec<Query>) -> Query) -> Query {
+    if items.len() == 1 {
+        items.remove(0)
+    } else {
+        group(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(fragment: &str) -> Query {
+        Query::Text(fragment.to_string())
+    }
+
+    /// Byte range of the first occurrence of `part` in `text`.
+    fn range(text: &str, part: &str) -> Range<usize> {
+        let start = text.find(part).expect(part);
+        start..start + part.len()
+    }
+
+    #[test]
+    fn parse_plain_text() {
+        assert_eq!(Query::parse("  любовь вера "), Ok(text("любовь вера")));
+    }
+
+    #[test]
+    fn parse_syntax() {
+        assert_eq!(
+            Query::parse("«Бог есть любовь» благослов*"),
+            Ok(Query::And(vec![
+                Query::Phrase(vec!["бог".into(), "есть".into(), "любовь".into()]),
+                Query::Prefix("благослов".into())
+            ]))
+        );
+        assert_eq!(
+            Query::parse("\"Любовь\" AND вера"),
+            Ok(Query::And(vec![Query::Word("любовь".into()), text("вера")]))
+        );
+    }
+
+    #[test]
+    fn parse_precedence() {
+        assert_eq!(
+            Query::parse("мир OR любовь AND вера"),
+            Ok(Query::Or(vec![
+                text("мир"),
+                Query::And(vec![text("любовь"), text("вера")])
+            ]))
+        );
+        assert_eq!(
+            Query::parse("(мир OR любовь) вера"),
+            Ok(Query::And(vec![
+                Query::Or(vec![text("мир"), text("любовь")]),
+                text("вера")
+            ]))
+        );
+        assert_eq!(
+            Query::parse("NOT мир любовь"),
+            Ok(Query::And(vec![
+                Query::Not(Box::new(text("мир"))),
+                text("любовь")
+            ]))
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |input| Query::parse(input).unwrap_err();
+        assert_eq!(error(" "), "Введите слово или фразу");
+        assert_eq!(error("() любовь"), "Пустые скобки");
+        assert_eq!(error("(любовь"), "Не закрыта скобка");
+        assert_eq!(error("любовь) вера*"), "Лишняя закрывающая скобка");
+        assert_eq!(error("любовь NOT"), "После NOT нужно слово");
+        assert_eq!(error("любовь OR"), "После OR нужно слово");
+        assert_eq!(error("AND любовь"), "Перед AND нужно слово");
+        assert_eq!(
+            error("NOT любовь"),
+            "Кроме NOT нужно хотя бы одно искомое слово"
+        );
+        assert_eq!(
+            error("бла*гослов"),
+            "Звёздочка ставится только в конце слова"
+        );
+        assert_eq!(error("б*"), "Перед * нужно хотя бы 2 буквы");
+        assert_eq!(error("\"любовь"), "Не закрыта кавычка");
+        assert_eq!(error("\"\" любовь"), "Пустые кавычки");
+    }
+
+    #[test]
+    fn matches() {
+        let query = Query::parse("\"есть любовь\" NOT страх").unwrap();
+        assert!(query.matches("Бог есть любовь"));
+        assert!(!query.matches("Бог есть любовь, а не страх"));
+        assert!(!query.matches("любовь есть"));
+
+        let query = Query::parse("благослов* OR Ёлка").unwrap();
+        assert!(query.matches("Благословен грядый"));
+        assert!(query.matches("зеленая елка"));
+        assert!(!query.matches("слово"));
+    }
+
+    #[test]
+    fn spans_are_sorted_and_merged() {
+        let verse = "Бог есть любовь, и любовь от Бога";
+        let query = Query::parse("\"есть любовь\" OR бог*").unwrap();
+        assert_eq!(
+            query.spans(verse),
+            vec![0..range(verse, ",").start, range(verse, "Бога")]
+        );
+
+        let query = Query::parse("любовь AND любов* NOT Бог*").unwrap();
+        let second = verse.rfind("любовь").unwrap();
+        assert_eq!(
+            query.spans(verse),
+            vec![range(verse, "любовь"), second..second + "любовь".len()]
+        );
+    }
+
+    #[test]
+    fn anchors() {
+        assert_eq!(Query::parse("любовь").unwrap().anchors(), vec!["любовь"]);
+        assert_eq!(
+            Query::parse("мир OR (вера любовь)").unwrap().anchors(),
+            vec!["мир", "любовь"]
+        );
+        assert_eq!(
+            Query::parse("благ* \"есть любовь\" NOT страх")
+                .unwrap()
+                .anchors(),
+            vec!["есть любовь"]
+        );
+    }
+}
@@ -0,0 +1,257 @@
+//! Scripture reference parsing and formatting.
+//!
+//! Understands the forms people actually type: `Ин 3:16`, `Рим 8 28`,
+//! `1 Кор 13`, `Мф 5-7`, `Ин 3:16-4:2` and English source codes like `ps 23`.
+
+use std::fmt;
+
+use gloo_storage::{LocalStorage, Storage};
+
+use crate::bible::{book_abbreviation, book_code, book_name};
+
+/// Number of canonical books.
+const BOOKS_COUNT: i16 = 66;
+
+const RECENT_KEY: &str = "bible_recent_refs";
+
+/// How many recently opened references are kept.
+const RECENT_LIMIT: usize = 8;
+
+/// A contiguous passage within one book.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reference {
+    /// Book ID (1-66).
+    pub book_id:     i16,
+    /// First chapter.
+    pub chapter:     i16,
+    /// First verse, `None` for whole chapters.
+    pub verse:       Option<i16>,
+    /// Last chapter (equals `chapter` for single-chapter passages).
+    pub end_chapter: i16,
+    /// Last verse, `None` for whole chapters.
+    pub end_verse:   Option<i16>
+}
+
+impl Reference {
+    /// Reference to a whole chapter.
+    #[must_use]
+    pub const fn chapter(book_id: i16, chapter: i16) -> Self {
+        Self {
+            book_id,
+            chapter,
+            verse: None,
+            end_chapter: chapter,
+            end_verse: None
+        }
+    }
+
+    /// Reference to a verse range within one chapter.
+    #[must_use]
+    pub const fn verses(book_id: i16, chapter: i16, start: i16, end: i16) -> Self {
+        Self {
+            book_id,
+            chapter,
+            verse: Some(start),
+            end_chapter: chapter,
+            end_verse: Some(end)
+        }
+    }
+
+    /// Reference to a whole chapter or to a single verse in it.
+    #[must_use]
+    pub const fn at(book_id: i16, chapter: i16, verse: Option<i16>) -> Self {
+        match verse {
+            Some(verse) => Self::verses(book_id, chapter, verse, verse),
+            None => Self::chapter(book_id, chapter)
+        }
+    }
+
+    /// Parses a reference such as `Ин 3:16-17`.
+    ///
+    /// Returns `None` if the book is unknown or no chapter is given.
+    #[must_use]
+    pub fn parse(input: &str) -> Option<Self> {
+        let (book, location) = split_book(input.trim())?;
+        let book_id = find_book(book)?;
+        parse_location(book_id, location)
+    }
+
+    /// Checks whether the passage includes the given verse.
+    #[must_use]
+    pub fn contains(&self, chapter: i16, verse: i16) -> bool {
+        let start = (self.chapter, self.verse.unwrap_or(1));
+        let end = (self.end_chapter, self.end_verse.unwrap_or(i16::MAX));
+        (start..=end).contains(&(chapter, verse))
+    }
+}
+
+impl fmt::Display for Reference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let abbrev = book_abbreviation(self.book_id).unwrap_or_default();
+        write!(f, "{abbrev} {}", self.chapter)?;
+
+        match (self.verse, self.end_verse) {
+            (Some(start), Some(end)) if self.end_chapter == self.chapter => {
+                write!(f, ":{start}")?;
+                if end != start {
+                    write!(f, "-{end}")?;
+                }
+            }
+            (Some(start), Some(end)) => write!(f, ":{start}-{}:{end}", self.end_chapter)?,
+            _ if self.end_chapter != self.chapter => write!(f, "-{}", self.end_chapter)?,
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+/// Recently opened references, newest first.
+#[must_use]
+pub fn recent_references() -> Vec<Reference> {
+    LocalStorage::get::<Vec<String>>(RECENT_KEY)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|s| Reference::parse(s))
+        .collect()
+}
+
+/// Moves a reference to the top of the recent list.
+pub fn remember_reference(reference: Reference) {
+    let mut recent = recent_references();
+    recent.retain(|r| *r != reference);
+    recent.insert(0, reference);
+    recent.truncate(RECENT_LIMIT);
+    let stored: Vec<String> = recent.iter().map(ToString::to_string).collect();
+    let _ = LocalStorage::set(RECENT_KEY, &stored);
+}
+
+/// Parses stored reference strings, skipping ones that don't parse.
+pub(crate) fn parse_references(references: &[String]) -> Vec<Reference> {
+    references
+        .iter()
+        .filter_map(|r| Reference::parse(r))
+        .collect()
+}
+
+/// Normalizes a book name for matching: lowercase, `ё` → `е`, no spaces or
+/// dots.
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !c.is_whitespace() && *c != '.')
+        .flat_map(char::to_lowercase)
+        .map(|c| if c == 'ё' { 'е' } else { c })
+        .collect()
+}
+
+/// Returns the normalized names a book can be referred to by.
+fn book_keys(book_id: i16) -> Vec<String> {
+    let mut keys = Vec::with_capacity(4);
+    if let Some(abbrev) = book_abbreviation(book_id) {
+        keys.push(normalize_name(abbrev));
+    }
+    if let Some(code) = book_code(book_id) {
+        keys.push(normalize_name(code));
+    }
+    if let Some(name) = book_name(book_id) {
+        let full = normalize_name(name);
+        // Gospels are commonly typed without "От"
+        if let Some(short) = full.strip_prefix("от") {
+            keys.push(short.to_string());
+        }
+        keys.push(full);
+    }
+    keys
+}
+
+/// Finds a book ID by name, abbreviation or English code.
+///
+/// Exact matches win; otherwise the first book in canonical order whose
+/// name starts with the input is returned.
+#[must_use]
+pub fn find_book(name: &str) -> Option<i16> {
+    let needle = normalize_name(name);
+    if needle.is_empty() {
+        return None;
+    }
+
+    (1..=BOOKS_COUNT)
+        .find(|&id| book_keys(id).contains(&needle))
+        .or_else(|| match_books(name).into_iter().next())
+}
+
+/// Returns IDs of books whose names start with the input, in canonical order.
+#[must_use]
+pub fn match_books(prefix: &str) -> Vec<i16> {
+    let needle = normalize_name(prefix);
+    if needle.is_empty() {
+        return Vec::new();
+    }
+
+    (1..=BOOKS_COUNT)
+        .filter(|&id| book_keys(id).iter().any(|k| k.starts_with(&needle)))
+        .collect()
+}
+
+/// Splits input into the book part and the chapter/verse part.
+///
+/// The book part ends at the first digit following a letter, so leading
+/// numbers like in `1 Кор` stay with the book.
+fn split_book(input: &str) -> Option<(&str, &str)> {
+    let mut seen_letter = false;
+    for (idx, c) in input.char_indices() {
+        if c.is_alphabetic() {
+            seen_letter = true;
+        } else if seen_letter && c.is_ascii_digit() {
+            return Some((input[..idx].trim(), input[idx..].trim()));
+        }
+    }
+    None
+}
+
+/// Parses `chapter[:verse]` into numbers.
+fn parse_point(part: &str) -> Option<(i16, Option<i16>)> {
+    let mut numbers = part
+        .split([':', '.', ',', ' '])
+        .filter(|s| !s.is_empty())
+        .map(str::parse::<i16>);
+
+    let first = numbers.next()?.ok().filter(|n| *n > 0)?;
+    let second = match numbers.next() {
+        Some(n) => Some(n.ok().filter(|n| *n > 0)?),
+        None => None
+    };
+
+    numbers.next().is_none().then_some((first, second))
+}
+
+/// Parses the location part of a reference for the given book.
+fn parse_location(book_id: i16, location: &str) -> Option<Reference> {
+    let mut parts = location.splitn(2, ['-', '–', '—']);
+    let start = parse_point(parts.next()?)?;
+    let end = parts.next().map(parse_point);
+
+    let reference = match (start, end) {
+        ((chapter, None), None) => Reference::chapter(book_id, chapter),
+        ((chapter, Some(verse)), None) => Reference::verses(book_id, chapter, verse, verse),
+        ((chapter, None), Some(Some((end_chapter, None)))) => Reference {
+            end_chapter,
+            ..Reference::chapter(book_id, chapter)
+        },
+        ((chapter, Some(verse)), Some(Some((end_verse, None)))) => {
+            Reference::verses(book_id, chapter, verse, end_verse)
+        }
+        ((chapter, Some(verse)), Some(Some((end_chapter, Some(end_verse))))) => Reference {
+            book_id,
+            chapter,
+            verse: Some(verse),
+            end_chapter,
+            end_verse: Some(end_verse)
+        },
+        _ => return None
+    };
+
+    let start = (reference.chapter, reference.verse.unwrap_or(0));
+    let end = (reference.end_chapter, reference.end_verse.unwrap_or(0));
+    (end >= start).then_some(reference)
+}
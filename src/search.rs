@@ -0,0 +1,285 @@
+//! Text matching shared by Bible search and find-in-chapter.
+
+use std::{
+    collections::BTreeSet,
+    ops::{Range, RangeInclusive}
+};
+
+use revelation_bible::Verse;
+
+use crate::{
+    bible::{BibleCache, book_name},
+    query::Query
+};
+
+/// Search result with the matched words located in the verse text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    /// Matching verse.
+    pub verse: Verse,
+    /// Byte ranges to highlight.
+    pub spans: Vec<Range<usize>>
+}
+
+impl SearchHit {
+    /// Locates the words of `query` in the verse.
+    #[must_use]
+    pub fn new(verse: Verse, query: &Query) -> Self {
+        let spans = query.spans(&verse.text);
+        Self {
+            verse,
+            spans
+        }
+    }
+}
+
+/// Named span of books offered as a search filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookRange {
+    /// Genesis to Malachi.
+    OldTestament,
+    /// Matthew to Revelation.
+    NewTestament,
+    /// The five books of Moses.
+    Law,
+    /// Joshua to Esther.
+    History,
+    /// Job to Song of Songs.
+    Wisdom,
+    /// Isaiah to Malachi.
+    Prophets,
+    /// The four Gospels.
+    Gospels,
+    /// The fourteen epistles of Paul, Romans to Hebrews.
+    Pauline,
+    /// The seven catholic epistles, James to Jude.
+    General
+}
+
+impl BookRange {
+    /// All ranges in the order they are offered.
+    pub const ALL: [Self; 9] = [
+        Self::OldTestament,
+        Self::NewTestament,
+        Self::Law,
+        Self::History,
+        Self::Wisdom,
+        Self::Prophets,
+        Self::Gospels,
+        Self::Pauline,
+        Self::General
+    ];
+
+    /// Filter chip label.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::OldTestament => "Ветхий Завет",
+            Self::NewTestament => "Новый Завет",
+            Self::Law => "Пятикнижие",
+            Self::History => "Исторические",
+            Self::Wisdom => "Учительные",
+            Self::Prophets => "Пророки",
+            Self::Gospels => "Евангелия",
+            Self::Pauline => "Послания Павла",
+            Self::General => "Соборные послания"
+        }
+    }
+
+    /// Book IDs in the range.
+    #[must_use]
+    pub const fn books(self) -> RangeInclusive<i16> {
+        match self {
+            Self::OldTestament => 1..=39,
+            Self::NewTestament => 40..=66,
+            Self::Law => 1..=5,
+            Self::History => 6..=17,
+            Self::Wisdom => 18..=22,
+            Self::Prophets => 23..=39,
+            Self::Gospels => 40..=43,
+            Self::Pauline => 45..=58,
+            Self::General => 59..=65
+        }
+    }
+}
+
+/// Books a search is limited to; empty means the whole Bible.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchFilter {
+    books: BTreeSet<i16>
+}
+
+impl SearchFilter {
+    /// Checks whether the filter lets every book through.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.books.is_empty()
+    }
+
+    /// Checks whether verses of a book pass the filter.
+    #[must_use]
+    pub fn allows(&self, book_id: i16) -> bool {
+        self.books.is_empty() || self.books.contains(&book_id)
+    }
+
+    /// Checks whether a book is picked explicitly.
+    #[must_use]
+    pub fn has_book(&self, book_id: i16) -> bool {
+        self.books.contains(&book_id)
+    }
+
+    /// Checks whether every book of a range is picked.
+    #[must_use]
+    pub fn has_range(&self, range: BookRange) -> bool {
+        range.books().all(|id| self.books.contains(&id))
+    }
+
+    /// Picked book IDs in canonical order.
+    pub fn books(&self) -> impl Iterator<Item = i16> + '_ {
+        self.books.iter().copied()
+    }
+
+    /// Picks or drops a book.
+    pub fn toggle_book(&mut self, book_id: i16) {
+        if !self.books.remove(&book_id) {
+            self.books.insert(book_id);
+        }
+    }
+
+    /// Drops a range if it is fully picked, otherwise picks all its books.
+    pub fn toggle_range(&mut self, range: BookRange) {
+        if self.has_range(range) {
+            for id in range.books() {
+                self.books.remove(&id);
+            }
+        } else {
+            self.books.extend(range.books());
+        }
+    }
+
+    /// Short description: the matching range name, one book or a count.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let exact = BookRange::ALL
+            .into_iter()
+            .find(|r| r.books().eq(self.books.iter().copied()));
+        match (exact, self.books.len()) {
+            (_, 0) => "Вся Библия".to_string(),
+            (Some(range), _) => range.label().to_string(),
+            (None, 1) => self
+                .books()
+                .next()
+                .and_then(book_name)
+                .unwrap_or_default()
+                .to_string(),
+            (None, count) => format!("Книг: {count}")
+        }
+    }
+}
+
+/// Folds a character for matching: lowercase, `ё` → `е`.
+#[must_use]
+pub fn fold_char(c: char) -> char {
+    match c.to_lowercase().next().unwrap_or(c) {
+        'ё' => 'е',
+        c => c
+    }
+}
+
+/// Normalizes text the way Bible search compares it.
+#[must_use]
+pub fn normalize(text: &str) -> String {
+    text.chars().map(fold_char).collect()
+}
+
+/// Finds non-overlapping matches of `query` in `text`, ignoring case and
+/// `ё`.
+///
+/// Returns byte ranges in the original `text`.
+#[must_use]
+pub fn find_matches(text: &str, query: &str) -> Vec<Range<usize>> {
+    let needle: Vec<char> = query.trim().chars().map(fold_char).collect();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    let chars: Vec<(usize, char)> = text
+        .char_indices()
+        .map(|(idx, c)| (idx, fold_char(c)))
+        .collect();
+
+    let mut matches = Vec::new();
+    let mut i = 0;
+    while i + needle.len() <= chars.len() {
+        if chars[i..i + needle.len()]
+            .iter()
+            .zip(&needle)
+            .all(|((_, c), n)| c == n)
+        {
+            let end = chars
+                .get(i + needle.len())
+                .map_or(text.len(), |(idx, _)| *idx);
+            matches.push(chars[i].0..end);
+            i += needle.len();
+        } else {
+            i += 1;
+        }
+    }
+    matches
+}
+
+/// Splits text into words, dropping punctuation.
+pub fn words(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !c.is_alphanumeric() && c != '-')
+        .map(|w| w.trim_matches('-'))
+        .filter(|w| !w.is_empty())
+}
+
+/// Offline symphony: verses containing the exact word form.
+#[must_use]
+pub fn concordance(bible: &BibleCache, word: &str) -> Vec<Verse> {
+    let word = normalize(word.trim());
+    if word.is_empty() {
+        return Vec::new();
+    }
+    bible
+        .verses()
+        .filter(|(_, text)| words(text).any(|w| normalize(w) == word))
+        .map(|((book_id, chapter, verse), text)| Verse {
+            id: 0,
+            book_id,
+            chapter,
+            verse,
+            text: text.to_string()
+        })
+        .collect()
+}
+
+/// Offline full-text search: verses matching the query, within the
+/// filtered books.
+#[must_use]
+pub fn search_offline(bible: &BibleCache, query: &Query, filter: &SearchFilter) -> Vec<SearchHit> {
+    bible
+        .verses()
+        .filter(|((book_id, ..), _)| filter.allows(*book_id))
+        .filter(|(_, text)| query.matches(text))
+        .map(|((book_id, chapter, verse), text)| {
+            let verse = Verse {
+                id: 0,
+                book_id,
+                chapter,
+                verse,
+                text: text.to_string()
+            };
+            SearchHit::new(verse, query)
+        })
+        .collect()
+}
+
+/// Byte ranges of the words in text, as split by [`words`].
+pub fn word_ranges(text: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let base = text.as_ptr() as usize;
+    words(text).map(move |w| {
+        let start = w.as_ptr() as usize - base;
+        start..start + w.len()
+    })
+}
@@ -0,0 +1,278 @@
+//! Copying and sharing passages with formatted references.
+
+use std::fmt::Write;
+
+use revelation_bible::Verse;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use web_sys::HtmlCanvasElement;
+
+use crate::reference::Reference;
+
+#[wasm_bindgen(inline_js = "
+export function canShare() {
+    return typeof navigator.share === 'function';
+}
+
+export async function shareText(text) {
+    try {
+        await navigator.share({ text });
+        return true;
+    } catch (e) {
+        // The user closed the share sheet
+        if (e.name === 'AbortError') {
+            return false;
+        }
+        throw e;
+    }
+}
+
+export async function copyText(text) {
+    if (navigator.clipboard && window.isSecureContext) {
+        await navigator.clipboard.writeText(text);
+        return;
+    }
+    const area = document.createElement('textarea');
+    area.value = text;
+    area.setAttribute('readonly', '');
+    area.style.position = 'fixed';
+    area.style.opacity = '0';
+    document.body.appendChild(area);
+    area.select();
+    const ok = document.execCommand('copy');
+    area.remove();
+    if (!ok) {
+        throw new Error('Clipboard unavailable');
+    }
+}
+
+export function canShareFiles() {
+    if (typeof navigator.canShare !== 'function') {
+        return false;
+    }
+    const probe = new File([''], 'probe.png', { type: 'image/png' });
+    return navigator.canShare({ files: [probe] });
+}
+
+export async function shareCanvas(canvas, filename, text) {
+    const blob = await new Promise((resolve) => canvas.toBlob(resolve, 'image/png'));
+    // toBlob hands back null when the canvas can't be encoded
+    if (!blob) {
+        throw new Error('Canvas encoding failed');
+    }
+    const file = new File([blob], filename, { type: 'image/png' });
+    try {
+        await navigator.share({ files: [file], text });
+        return true;
+    } catch (e) {
+        // The user closed the share sheet
+        if (e.name === 'AbortError') {
+            return false;
+        }
+        throw e;
+    }
+}
+
+export function downloadCanvas(canvas, filename) {
+    const link = document.createElement('a');
+    link.href = canvas.toDataURL('image/png');
+    link.download = filename;
+    link.click();
+}
+")]
+extern "C" {
+    fn canShare() -> bool;
+    #[wasm_bindgen(catch)]
+    async fn shareText(text: &str) -> Result<JsValue, JsValue>;
+    #[wasm_bindgen(catch)]
+    async fn copyText(text: &str) -> Result<(), JsValue>;
+    fn canShareFiles() -> bool;
+    #[wasm_bindgen(catch)]
+    async fn shareCanvas(
+        canvas: &HtmlCanvasElement,
+        filename: &str,
+        text: &str
+    ) -> Result<JsValue, JsValue>;
+    fn downloadCanvas(canvas: &HtmlCanvasElement, filename: &str);
+}
+
+/// How copied passages are formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CopyStyle {
+    /// Text followed by the reference.
+    #[default]
+    Plain,
+    /// Text with verse numbers.
+    Numbered,
+    /// Markdown quote with a bold reference.
+    Markdown
+}
+
+impl CopyStyle {
+    /// All styles in display order.
+    pub const ALL: [Self; 3] = [Self::Plain, Self::Numbered, Self::Markdown];
+
+    /// Short label for settings.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Plain => "Текст",
+            Self::Numbered => "С номерами",
+            Self::Markdown => "Markdown"
+        }
+    }
+}
+
+/// What happened to shared text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareOutcome {
+    /// Handed over to the system share sheet.
+    Shared,
+    /// Copied to the clipboard.
+    Copied,
+    /// The share sheet was closed without sharing.
+    Cancelled
+}
+
+/// Builds a reference like `Ин 3:16-17,19` for verses of one chapter.
+///
+/// Returns `None` if `verses` is empty.
+#[must_use]
+pub fn citation(verses: &[Verse]) -> Option<String> {
+    let first = verses.first()?;
+    let mut numbers: Vec<i16> = verses.iter().map(|v| v.verse).collect();
+    numbers.sort_unstable();
+    numbers.dedup();
+
+    let mut ranges: Vec<(i16, i16)> = Vec::new();
+    for n in numbers {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == n => *end = n,
+            _ => ranges.push((n, n))
+        }
+    }
+
+    let mut ranges = ranges.into_iter();
+    let (start, end) = ranges.next()?;
+    let mut result = Reference::verses(first.book_id, first.chapter, start, end).to_string();
+    for (start, end) in ranges {
+        if start == end {
+            let _ = write!(result, ",{start}");
+        } else {
+            let _ = write!(result, ",{start}-{end}");
+        }
+    }
+
+    Some(result)
+}
+
+/// Joins verse texts without numbers.
+#[must_use]
+pub fn passage_text(verses: &[Verse]) -> String {
+    join_verses(verses, |v| v.text.trim().to_string())
+}
+
+/// Joins verses in order, marking skipped verses with an ellipsis.
+fn join_verses(verses: &[Verse], text: impl Fn(&Verse) -> String) -> String {
+    let mut result = String::new();
+    let mut previous = None;
+    for verse in verses {
+        if let Some(previous) = previous {
+            result.push_str(if verse.verse == previous + 1 {
+                " "
+            } else {
+                " … "
+            });
+        }
+        result.push_str(&text(verse));
+        previous = Some(verse.verse);
+    }
+    result
+}
+
+/// Formats verses of one chapter with their reference.
+///
+/// Returns `None` if `verses` is empty.
+#[must_use]
+pub fn format_passage(verses: &[Verse], style: CopyStyle) -> Option<String> {
+    let reference = citation(verses)?;
+
+    let text = match style {
+        CopyStyle::Plain | CopyStyle::Markdown => passage_text(verses),
+        CopyStyle::Numbered => join_verses(verses, |v| format!("{} {}", v.verse, v.text.trim()))
+    };
+
+    Some(match style {
+        CopyStyle::Plain | CopyStyle::Numbered => format!("{text} ({reference})"),
+        CopyStyle::Markdown => format!("> {text}\n>\n> — **{reference}**")
+    })
+}
+
+/// Checks whether the Web Share API is available.
+#[must_use]
+pub fn is_share_supported() -> bool {
+    canShare()
+}
+
+/// Copies text to the clipboard.
+///
+/// # Errors
+///
+/// Returns error string if the clipboard is unavailable.
+pub async fn copy(text: &str) -> Result<(), String> {
+    copyText(text)
+        .await
+        .map_err(|e| format!("Clipboard error: {e:?}"))
+}
+
+/// Shares text through the Web Share API, falling back to the clipboard.
+///
+/// # Errors
+///
+/// Returns error string if sharing failed or the clipboard is unavailable.
+pub async fn share(text: &str) -> Result<ShareOutcome, String> {
+    if !is_share_supported() {
+        return copy(text).await.map(|()| ShareOutcome::Copied);
+    }
+
+    shareText(text)
+        .await
+        .map(|shared| shared_outcome(&shared))
+        .map_err(|e| format!("Share error: {e:?}"))
+}
+
+/// Checks whether images can be shared through the Web Share API.
+#[must_use]
+pub fn is_file_share_supported() -> bool {
+    canShareFiles()
+}
+
+/// Shares the canvas as a PNG file with an accompanying text.
+///
+/// # Errors
+///
+/// Returns error string if sharing failed.
+pub async fn share_image(
+    canvas: &HtmlCanvasElement,
+    filename: &str,
+    text: &str
+) -> Result<ShareOutcome, String> {
+    shareCanvas(canvas, filename, text)
+        .await
+        .map(|shared| shared_outcome(&shared))
+        .map_err(|e| format!("Share error: {e:?}"))
+}
+
+/// Reads whether the share sheet completed; `false` means it was closed.
+fn shared_outcome(shared: &JsValue) -> ShareOutcome {
+    if shared.as_bool().unwrap_or(true) {
+        ShareOutcome::Shared
+    } else {
+        ShareOutcome::Cancelled
+    }
+}
+
+/// Saves the canvas as a PNG file.
+pub fn download_image(canvas: &HtmlCanvasElement, filename: &str) {
+    downloadCanvas(canvas, filename);
+}
@@ -0,0 +1,65 @@
+//! Speech synthesis - reads text aloud with a Russian voice.
+
+use wasm_bindgen::{closure::Closure, prelude::*};
+
+#[wasm_bindgen(inline_js = "
+function russianVoice() {
+    const voices = window.speechSynthesis.getVoices();
+    return voices.find((v) => v.lang === 'ru-RU' && v.localService)
+        || voices.find((v) => v.lang && v.lang.toLowerCase().startsWith('ru'))
+        || null;
+}
+
+export function isSpeechSupported() {
+    return 'speechSynthesis' in window && 'SpeechSynthesisUtterance' in window;
+}
+
+export function speak(text, rate, onEnd) {
+    const utterance = new SpeechSynthesisUtterance(text);
+    utterance.lang = 'ru-RU';
+    utterance.rate = rate;
+    const voice = russianVoice();
+    if (voice) {
+        utterance.voice = voice;
+    }
+    let done = false;
+    const finish = () => {
+        if (!done) {
+            done = true;
+            onEnd();
+        }
+    };
+    utterance.onend = finish;
+    utterance.onerror = finish;
+    window.speechSynthesis.speak(utterance);
+}
+
+export function cancelSpeech() {
+    window.speechSynthesis.cancel();
+}
+")]
+extern "C" {
+    fn isSpeechSupported() -> bool;
+    #[wasm_bindgen(js_name = "speak")]
+    fn speak_js(text: &str, rate: f64, on_end: JsValue);
+    fn cancelSpeech();
+}
+
+/// Available speech rates.
+pub const RATES: [f64; 5] = [0.75, 1.0, 1.25, 1.5, 2.0];
+
+/// Check if speech synthesis is supported.
+#[must_use]
+pub fn is_supported() -> bool {
+    isSpeechSupported()
+}
+
+/// Speaks text, calling `on_end` once when it finishes or is interrupted.
+pub fn speak(text: &str, rate: f64, on_end: impl FnOnce() + 'static) {
+    speak_js(text, rate, Closure::once_into_js(on_end));
+}
+
+/// Stops speech and clears the queue.
+pub fn cancel() {
+    cancelSpeech();
+}
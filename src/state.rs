@@ -6,12 +6,23 @@ use revelation_user::RUser;
 use uuid::Uuid;
 use wasm_bindgen_futures::spawn_local;
 
-use crate::bible::{BibleCache, BibleProvider};
+use crate::{
+    audio::ListenSource,
+    bible::{BibleCache, BibleProvider, Translation},
+    calendar::CalendarStyle,
+    share::CopyStyle
+};
 
 const USER_ID_KEY: &str = "revelation_user_id";
 const BIBLE_BOOK_KEY: &str = "bible_current_book";
 const BIBLE_CHAPTER_KEY: &str = "bible_current_chapter";
 const ONLY_WITH_CHORDS_KEY: &str = "songs_only_with_chords";
+const COPY_STYLE_KEY: &str = "bible_copy_style";
+const SPEECH_RATE_KEY: &str = "bible_speech_rate";
+const LISTEN_SOURCE_KEY: &str = "bible_listen_source";
+const CALENDAR_STYLE_KEY: &str = "church_calendar_style";
+const PARALLEL_KEY: &str = "bible_parallel";
+const LINK_NAMES_KEY: &str = "bible_link_names";
 
 /// Global application state.
 #[derive(Clone)]
@@ -28,15 +39,36 @@ pub struct AppState {
     pub current_book:      RwSignal<i16>,
     /// Current Bible chapter number.
     pub current_chapter:   RwSignal<i16>,
+    /// Verse to scroll to and highlight in the reader.
+    pub focus_verse:       RwSignal<Option<i16>>,
+    /// Books panel open state in the reader.
+    pub books_open:        RwSignal<bool>,
+    /// Find-in-chapter bar open state in the reader.
+    pub find_open:         RwSignal<bool>,
     /// Cached Bible data.
     pub bible:             RwSignal<Option<BibleCache>>,
     /// Filter for songs with chords only.
-    pub only_with_chords:  RwSignal<bool>
+    pub only_with_chords:  RwSignal<bool>,
+    /// Format of copied and shared verses.
+    pub copy_style:        RwSignal<CopyStyle>,
+    /// Speech rate for listen mode.
+    pub speech_rate:       RwSignal<f64>,
+    /// Recorded audio or speech synthesis in listen mode.
+    pub listen_source:     RwSignal<ListenSource>,
+    /// Church calendar for fixed feasts.
+    pub calendar_style:    RwSignal<CalendarStyle>,
+    /// Translations shown next to the Synodal text in the reader.
+    pub parallel:          RwSignal<Vec<Translation>>,
+    /// Loaded parallel translations, kept in memory only.
+    pub parallel_bibles:   RwSignal<Vec<(Translation, BibleCache)>>,
+    /// Link names of people, places and terms to the dictionary.
+    pub link_names:        RwSignal<bool>
 }
 
 impl AppState {
     /// Initializes application state from localStorage.
     #[must_use]
+    #[allow(clippy::too_many_lines)]
     pub fn init() -> Self {
         let user_id = LocalStorage::get::<String>(USER_ID_KEY)
             .ok()
@@ -50,6 +82,15 @@ impl AppState {
         let saved_book = LocalStorage::get::<i16>(BIBLE_BOOK_KEY).unwrap_or(1);
         let saved_chapter = LocalStorage::get::<i16>(BIBLE_CHAPTER_KEY).unwrap_or(1);
         let saved_chords_filter = LocalStorage::get::<bool>(ONLY_WITH_CHORDS_KEY).unwrap_or(false);
+        let saved_copy_style = LocalStorage::get::<CopyStyle>(COPY_STYLE_KEY).unwrap_or_default();
+        let saved_speech_rate = LocalStorage::get::<f64>(SPEECH_RATE_KEY).unwrap_or(1.0);
+        let saved_listen_source =
+            LocalStorage::get::<ListenSource>(LISTEN_SOURCE_KEY).unwrap_or_default();
+        let saved_calendar_style =
+            LocalStorage::get::<CalendarStyle>(CALENDAR_STYLE_KEY).unwrap_or_default();
+        let saved_parallel =
+            LocalStorage::get::<Vec<Translation>>(PARALLEL_KEY).unwrap_or_default();
+        let saved_link_names = LocalStorage::get::<bool>(LINK_NAMES_KEY).unwrap_or(false);
 
         let bible = RwSignal::new(None);
 
@@ -66,6 +107,13 @@ impl AppState {
         let current_book = RwSignal::new(saved_book);
         let current_chapter = RwSignal::new(saved_chapter);
         let only_with_chords = RwSignal::new(saved_chords_filter);
+        let copy_style = RwSignal::new(saved_copy_style);
+        let speech_rate = RwSignal::new(saved_speech_rate);
+        let listen_source = RwSignal::new(saved_listen_source);
+        let calendar_style = RwSignal::new(saved_calendar_style);
+        let parallel = RwSignal::new(saved_parallel);
+        let parallel_bibles = RwSignal::new(Vec::<(Translation, BibleCache)>::new());
+        let link_names = RwSignal::new(saved_link_names);
 
         Effect::new(move |_| {
             let book = current_book.get();
@@ -79,6 +127,45 @@ impl AppState {
             let _ = LocalStorage::set(ONLY_WITH_CHORDS_KEY, filter);
         });
 
+        Effect::new(move |_| {
+            let _ = LocalStorage::set(COPY_STYLE_KEY, copy_style.get());
+        });
+
+        Effect::new(move |_| {
+            let _ = LocalStorage::set(SPEECH_RATE_KEY, speech_rate.get());
+        });
+
+        Effect::new(move |_| {
+            let _ = LocalStorage::set(LISTEN_SOURCE_KEY, listen_source.get());
+        });
+
+        Effect::new(move |_| {
+            let _ = LocalStorage::set(CALENDAR_STYLE_KEY, calendar_style.get());
+        });
+
+        Effect::new(move |_| {
+            let _ = LocalStorage::set(LINK_NAMES_KEY, link_names.get());
+        });
+
+        Effect::new(move |_| {
+            let selected = parallel.get();
+            let _ = LocalStorage::set(PARALLEL_KEY, &selected);
+            // Fetch translations that are not loaded yet
+            for translation in selected {
+                if parallel_bibles.with_untracked(|b| b.iter().any(|(t, _)| *t == translation)) {
+                    continue;
+                }
+                spawn_local(async move {
+                    match BibleProvider::fetch_translation(translation).await {
+                        Ok(cache) => parallel_bibles.update(|b| b.push((translation, cache))),
+                        Err(e) => web_sys::console::error_1(
+                            &format!("Failed to load {}: {e}", translation.label()).into()
+                        )
+                    }
+                });
+            }
+        });
+
         Self {
             user_id: RwSignal::new(user_id),
             user: RwSignal::new(None),
@@ -86,8 +173,18 @@ impl AppState {
             sidebar_collapsed: RwSignal::new(false),
             current_book,
             current_chapter,
+            focus_verse: RwSignal::new(None),
+            books_open: RwSignal::new(false),
+            find_open: RwSignal::new(false),
             bible,
-            only_with_chords
+            only_with_chords,
+            copy_style,
+            speech_rate,
+            listen_source,
+            calendar_style,
+            parallel,
+            parallel_bibles,
+            link_names
         }
     }
 
@@ -0,0 +1,110 @@
+//! Local reading history - time spent, streaks and completed chapters.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{Days, NaiveDate};
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+const HISTORY_KEY: &str = "reading_history";
+
+/// Reading activity stored on the device.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadingHistory {
+    /// Seconds spent in the reader per day.
+    pub days:      BTreeMap<NaiveDate, u32>,
+    /// Chapters marked as read, as `(book_id, chapter)`.
+    pub chapters:  BTreeSet<(i16, i16)>,
+    /// Days on which at least one chapter was marked as read.
+    #[serde(default)]
+    pub read_days: BTreeSet<NaiveDate>
+}
+
+impl ReadingHistory {
+    /// Loads history from `LocalStorage`.
+    #[must_use]
+    pub fn load() -> Self {
+        LocalStorage::get(HISTORY_KEY).unwrap_or_default()
+    }
+
+    /// Applies `f` to the stored history and saves it back.
+    pub fn update(f: impl FnOnce(&mut Self)) -> Self {
+        let mut history = Self::load();
+        f(&mut history);
+        let _ = LocalStorage::set(HISTORY_KEY, &history);
+        history
+    }
+
+    /// Adds reading time to a day.
+    pub fn add_time(&mut self, date: NaiveDate, seconds: u32) {
+        let total = self.days.entry(date).or_default();
+        *total = total.saturating_add(seconds);
+    }
+
+    /// Marks a chapter read or unread; marking counts `date` towards the
+    /// streak.
+    pub fn toggle_chapter(&mut self, book_id: i16, chapter: i16, date: NaiveDate) {
+        if !self.chapters.remove(&(book_id, chapter)) {
+            self.chapters.insert((book_id, chapter));
+            self.read_days.insert(date);
+        }
+    }
+
+    /// Checks whether a chapter is marked as read.
+    #[must_use]
+    pub fn is_read(&self, book_id: i16, chapter: i16) -> bool {
+        self.chapters.contains(&(book_id, chapter))
+    }
+
+    /// Number of read chapters in a book.
+    #[must_use]
+    pub fn read_in_book(&self, book_id: i16) -> usize {
+        self.chapters
+            .range((book_id, i16::MIN)..=(book_id, i16::MAX))
+            .count()
+    }
+
+    /// Total reading time in minutes.
+    #[must_use]
+    pub fn total_minutes(&self) -> u32 {
+        self.days.values().sum::<u32>() / 60
+    }
+
+    /// Consecutive days with a chapter marked as read, ending today, or
+    /// yesterday if no chapter is marked today yet.
+    #[must_use]
+    pub fn current_streak(&self, today: NaiveDate) -> u32 {
+        let start = if self.read_days.contains(&today) {
+            Some(today)
+        } else {
+            today.pred_opt()
+        };
+        let mut count = 0;
+        let mut day = start;
+        while let Some(date) = day
+            && self.read_days.contains(&date)
+        {
+            count += 1;
+            day = date.checked_sub_days(Days::new(1));
+        }
+        count
+    }
+
+    /// Longest run of consecutive days with a chapter marked as read.
+    #[must_use]
+    pub fn longest_streak(&self) -> u32 {
+        let mut longest = 0;
+        let mut run = 0;
+        let mut prev: Option<NaiveDate> = None;
+        for &date in &self.read_days {
+            run = if prev.and_then(|p| p.succ_opt()) == Some(date) {
+                run + 1
+            } else {
+                1
+            };
+            longest = longest.max(run);
+            prev = Some(date);
+        }
+        longest
+    }
+}
@@ -0,0 +1,368 @@
+//! Verse image cards rendered on a canvas for sharing in chats and stories.
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsCast;
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
+
+/// Card aspect ratio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CardFormat {
+    /// 1:1, for chats and feeds.
+    #[default]
+    Square,
+    /// 9:16, for stories.
+    Story
+}
+
+impl CardFormat {
+    /// All formats in display order.
+    pub const ALL: [Self; 2] = [Self::Square, Self::Story];
+
+    /// Canvas size in pixels.
+    #[must_use]
+    pub const fn size(self) -> (u32, u32) {
+        match self {
+            Self::Square => (1080, 1080),
+            Self::Story => (1080, 1920)
+        }
+    }
+
+    /// Short label for the picker.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Square => "Квадрат",
+            Self::Story => "Сторис"
+        }
+    }
+}
+
+/// Card background preset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Background {
+    /// Colors of the current app theme.
+    #[default]
+    Theme,
+    /// Old paper.
+    Parchment,
+    /// Warm sunrise gradient.
+    Dawn,
+    /// Deep blue gradient.
+    Night,
+    /// Green gradient.
+    Olive
+}
+
+impl Background {
+    /// All backgrounds in display order.
+    pub const ALL: [Self; 5] = [
+        Self::Theme,
+        Self::Parchment,
+        Self::Dawn,
+        Self::Night,
+        Self::Olive
+    ];
+
+    /// Short label for the picker.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Theme => "Тема",
+            Self::Parchment => "Пергамент",
+            Self::Dawn => "Рассвет",
+            Self::Night => "Ночь",
+            Self::Olive => "Олива"
+        }
+    }
+
+    /// CSS background for the picker swatch.
+    #[must_use]
+    pub fn swatch(self) -> String {
+        let palette = self.palette();
+        match palette.end {
+            Some(end) => format!("linear-gradient(135deg, {}, {end})", palette.start),
+            None => palette.start
+        }
+    }
+
+    /// Text color matching the background.
+    #[must_use]
+    pub fn text_color(self) -> String {
+        self.palette().text
+    }
+
+    /// Accent color matching the background.
+    #[must_use]
+    pub fn accent_color(self) -> String {
+        self.palette().accent
+    }
+
+    /// Resolves preset colors.
+    fn palette(self) -> Palette {
+        let fixed = |start: &str, end: Option<&str>, text: &str, accent: &str| Palette {
+            start:  start.to_string(),
+            end:    end.map(ToString::to_string),
+            text:   text.to_string(),
+            accent: accent.to_string()
+        };
+
+        match self {
+            Self::Theme => Palette {
+                start:  css_var("--bg").unwrap_or_else(|| "#ffffff".to_string()),
+                end:    css_var("--bg-secondary"),
+                text:   css_var("--text").unwrap_or_else(|| "#1a1a1a".to_string()),
+                accent: css_var("--accent-text").unwrap_or_else(|| "#a07c1a".to_string())
+            },
+            Self::Parchment => fixed("#f4ecd8", Some("#e8dcc0"), "#4a3b28", "#8b5e1a"),
+            Self::Dawn => fixed("#f6d365", Some("#fda085"), "#3d2914", "#7a3b10"),
+            Self::Night => fixed("#0f2027", Some("#2c5364"), "#f2f2f2", "#c9a227"),
+            Self::Olive => fixed("#1e3c2b", Some("#5a7d4f"), "#f4f1e8", "#e3c77a")
+        }
+    }
+}
+
+/// Typography preset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Typography {
+    /// Serif text, like the reader.
+    #[default]
+    Serif,
+    /// Sans-serif text.
+    Sans,
+    /// Serif italic.
+    Italic
+}
+
+impl Typography {
+    /// All presets in display order.
+    pub const ALL: [Self; 3] = [Self::Serif, Self::Sans, Self::Italic];
+
+    /// Short label for the picker.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Serif => "Serif",
+            Self::Sans => "Sans",
+            Self::Italic => "Курсив"
+        }
+    }
+
+    /// Canvas font for the given pixel size, using the theme font stacks.
+    fn font(self, size: f64) -> String {
+        let serif = css_var("--font-serif").unwrap_or_else(|| "Georgia, serif".to_string());
+        let sans = css_var("--font-sans").unwrap_or_else(|| "system-ui, sans-serif".to_string());
+        match self {
+            Self::Serif => format!("{size}px {serif}"),
+            Self::Sans => format!("{size}px {sans}"),
+            Self::Italic => format!("italic {size}px {serif}")
+        }
+    }
+
+    /// Canvas font for the reference line.
+    fn reference_font(self, size: f64) -> String {
+        let family = match self {
+            Self::Sans => css_var("--font-sans").unwrap_or_else(|| "sans-serif".to_string()),
+            Self::Serif | Self::Italic => {
+                css_var("--font-serif").unwrap_or_else(|| "serif".to_string())
+            }
+        };
+        format!("600 {size}px {family}")
+    }
+}
+
+/// Card appearance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CardStyle {
+    /// Aspect ratio.
+    pub format:     CardFormat,
+    /// Background preset.
+    pub background: Background,
+    /// Typography preset.
+    pub typography: Typography
+}
+
+/// Resolved card colors.
+struct Palette {
+    start:  String,
+    end:    Option<String>,
+    text:   String,
+    accent: String
+}
+
+/// Largest text size relative to the card width.
+const MAX_FONT_RATIO: f64 = 0.075;
+/// Smallest text size relative to the card width.
+const MIN_FONT_RATIO: f64 = 0.028;
+/// Line height relative to the font size.
+const LINE_HEIGHT: f64 = 1.4;
+/// Footer label drawn at the bottom of every card.
+const WATERMARK: &str = "Revelation";
+
+/// Renders `text` and its `reference` onto the canvas.
+///
+/// The canvas is resized to the card format and the font is shrunk until the
+/// text fits. Text that overflows even at the smallest size is cut off with
+/// an ellipsis.
+///
+/// # Errors
+///
+/// Returns error string if the 2D context is unavailable.
+pub fn render(
+    canvas: &HtmlCanvasElement,
+    text: &str,
+    reference: &str,
+    style: CardStyle
+) -> Result<(), String> {
+    let (width, height) = style.format.size();
+    canvas.set_width(width);
+    canvas.set_height(height);
+
+    let ctx = canvas
+        .get_context("2d")
+        .ok()
+        .flatten()
+        .and_then(|ctx| ctx.dyn_into::<CanvasRenderingContext2d>().ok())
+        .ok_or("Canvas 2D context unavailable")?;
+
+    let width = f64::from(width);
+    let height = f64::from(height);
+    let palette = style.background.palette();
+
+    fill_background(&ctx, &palette, width, height);
+
+    let padding = width * 0.1;
+    let max_width = width - padding * 2.0;
+    let reference_size = (width * 0.045).round();
+    let footer = reference_size * 3.0;
+    let max_height = height - padding * 2.0 - footer;
+
+    let mut size = (width * MAX_FONT_RATIO).round();
+    let mut lines;
+    loop {
+        ctx.set_font(&style.typography.font(size));
+        lines = wrap_text(&ctx, text, max_width);
+        let fits = line_count(&lines) * size * LINE_HEIGHT <= max_height;
+        if fits || size <= width * MIN_FONT_RATIO {
+            break;
+        }
+        size -= 2.0;
+    }
+    let line_height = size * LINE_HEIGHT;
+    truncate(&ctx, &mut lines, max_height / line_height, max_width);
+
+    let block = line_count(&lines).mul_add(line_height, reference_size * 2.0);
+    let mut y = ((height - block) / 2.0).max(padding);
+
+    ctx.set_text_align("center");
+    ctx.set_text_baseline("top");
+    ctx.set_fill_style_str(&palette.text);
+    for line in &lines {
+        let _ = ctx.fill_text(line, width / 2.0, y);
+        y += line_height;
+    }
+
+    y += reference_size;
+    ctx.set_font(&style.typography.reference_font(reference_size));
+    ctx.set_fill_style_str(&palette.accent);
+    let _ = ctx.fill_text(reference, width / 2.0, y);
+
+    ctx.set_global_alpha(0.5);
+    ctx.set_font(&style.typography.reference_font(reference_size * 0.6));
+    ctx.set_fill_style_str(&palette.text);
+    let _ = ctx.fill_text(WATERMARK, width / 2.0, height - padding * 0.6);
+    ctx.set_global_alpha(1.0);
+
+    Ok(())
+}
+
+/// Number of wrapped lines as a float for layout math.
+fn line_count(lines: &[String]) -> f64 {
+    f64::from(u32::try_from(lines.len()).unwrap_or(u32::MAX))
+}
+
+/// Fills the canvas with a solid color or a diagonal gradient.
+fn fill_background(ctx: &CanvasRenderingContext2d, palette: &Palette, width: f64, height: f64) {
+    match &palette.end {
+        Some(end) => {
+            let gradient = ctx.create_linear_gradient(0.0, 0.0, width, height);
+            let _ = gradient.add_color_stop(0.0, &palette.start);
+            let _ = gradient.add_color_stop(1.0, end);
+            ctx.set_fill_style_canvas_gradient(&gradient);
+        }
+        None => ctx.set_fill_style_str(&palette.start)
+    }
+    ctx.fill_rect(0.0, 0.0, width, height);
+}
+
+/// Splits text into lines no wider than `max_width` with the current font.
+fn wrap_text(ctx: &CanvasRenderingContext2d, text: &str, max_width: f64) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+        let candidate = if line.is_empty() {
+            word.to_string()
+        } else {
+            format!("{line} {word}")
+        };
+        let too_wide = ctx
+            .measure_text(&candidate)
+            .is_ok_and(|m| m.width() > max_width);
+        if too_wide && !line.is_empty() {
+            lines.push(std::mem::replace(&mut line, word.to_string()));
+        } else {
+            line = candidate;
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Drops lines beyond `max_lines` and ends the last kept one with an ellipsis
+/// that still fits into `max_width`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn truncate(
+    ctx: &CanvasRenderingContext2d,
+    lines: &mut Vec<String>,
+    max_lines: f64,
+    max_width: f64
+) {
+    let max_lines = max_lines.floor().max(1.0) as usize;
+    if lines.len() <= max_lines {
+        return;
+    }
+    lines.truncate(max_lines);
+    let Some(last) = lines.last_mut() else {
+        return;
+    };
+    loop {
+        let candidate = format!("{}…", last.trim_end_matches([',', ';', ':', '.']));
+        let fits = ctx
+            .measure_text(&candidate)
+            .is_ok_and(|m| m.width() <= max_width);
+        match last.rfind(' ') {
+            Some(space) if !fits => last.truncate(space),
+            _ => {
+                *last = candidate;
+                break;
+            }
+        }
+    }
+}
+
+/// Reads a CSS custom property from the themed document body.
+fn css_var(name: &str) -> Option<String> {
+    let window = web_sys::window()?;
+    let body = window.document()?.body()?;
+    let value = window
+        .get_computed_style(&body)
+        .ok()
+        .flatten()?
+        .get_property_value(name)
+        .ok()?;
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
@@ -0,0 +1,185 @@
+//! Versification schemes - maps verses between translations.
+//!
+//! Every scheme is mapped through the KJV numbering. The Synodal scheme
+//! differs in the Psalms (LXX chapter numbers, titles counted as verses),
+//! in the chapter breaks of Numbers 29-30, Job 39-41, Ecclesiastes 4-5,
+//! Song of Songs 6-7, Hosea 13-14 and Jonah 1-2, in the closing verses of
+//! 2 Corinthians and 3 John, and in the Romans doxology. Numbers 16-17 and
+//! 3 Kings 4-5, broken differently in the Hebrew text, follow the KJV here.
+//! Other books align by verse number; a verse left without a counterpart
+//! still gets a row of its own in the parallel view.
+
+use serde::{Deserialize, Serialize};
+
+const PSALMS: i16 = 19;
+const ROMANS: i16 = 45;
+
+/// Synodal verse runs numbered differently from the KJV, as
+/// `(book, chapter, first verse, last verse, KJV chapter, KJV first verse)`.
+const SYNODAL_SHIFTS: [(i16, i16, i16, i16, i16, i16); 15] = [
+    // Numbers 30:1 closes KJV chapter 29
+    (4, 30, 1, 1, 29, 40),
+    (4, 30, 2, 17, 30, 1),
+    // Job 39:31 - 41:26 (LXX breaks)
+    (18, 39, 31, 35, 40, 1),
+    (18, 40, 1, 19, 40, 6),
+    (18, 40, 20, 27, 41, 1),
+    (18, 41, 1, 26, 41, 9),
+    // Ecclesiastes 4:17 opens KJV chapter 5
+    (21, 4, 17, 17, 5, 1),
+    (21, 5, 1, 19, 5, 2),
+    // Song of Songs 7:1 closes KJV chapter 6
+    (22, 7, 1, 1, 6, 13),
+    (22, 7, 2, 14, 7, 1),
+    // Hosea 14:1 closes KJV chapter 13
+    (28, 14, 1, 1, 13, 16),
+    (28, 14, 2, 10, 14, 1),
+    // Jonah 2:1 closes KJV chapter 1
+    (32, 2, 1, 1, 1, 17),
+    (32, 2, 2, 11, 2, 1),
+    // 2 Corinthians 13:13 is KJV 13:14, see `KJV_MERGED`
+    (47, 13, 13, 13, 13, 14)
+];
+
+/// KJV verses whose text ends the previous Synodal verse, as
+/// `(KJV verse, Synodal verse)`.
+const KJV_MERGED: [(VerseKey, VerseKey); 1] = [
+    // 2 Corinthians 13:12-13
+    ((47, 13, 13), (47, 13, 12))
+];
+
+/// Synodal verses whose text ends the previous KJV verse.
+const SYNODAL_MERGED: [VerseKey; 1] = [
+    // 3 John 14-15
+    (64, 1, 15)
+];
+
+/// Psalms (Hebrew numbering) without a superscription.
+const UNTITLED_PSALMS: [i16; 34] = [
+    1, 2, 10, 33, 43, 71, 91, 93, 94, 95, 96, 97, 99, 104, 105, 106, 107, 111, 112, 113, 114, 115,
+    116, 117, 118, 119, 135, 136, 137, 146, 147, 148, 149, 150
+];
+
+/// Psalms (Hebrew numbering) whose superscription spans two verses.
+const LONG_TITLE_PSALMS: [i16; 4] = [51, 52, 54, 60];
+
+/// Verse location as `(book_id, chapter, verse)`.
+pub type VerseKey = (i16, i16, i16);
+
+/// Verse numbering used by a translation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Versification {
+    /// Russian Synodal numbering.
+    Synodal,
+    /// King James numbering, used as the common reference.
+    Kjv
+}
+
+impl Versification {
+    /// Converts a verse to KJV numbering.
+    ///
+    /// Returns `None` for verses without a counterpart, such as psalm
+    /// titles.
+    #[must_use]
+    pub fn to_kjv(self, key: VerseKey) -> Option<VerseKey> {
+        match (self, key) {
+            (Self::Synodal, (PSALMS, chapter, verse)) => {
+                let (chapter, verse) = synodal_to_hebrew_psalm(chapter, verse);
+                let verse = verse - psalm_title_verses(chapter);
+                (verse >= 1).then_some((PSALMS, chapter, verse))
+            }
+            (Self::Synodal, (ROMANS, 14, verse @ 24..=26)) => Some((ROMANS, 16, verse + 1)),
+            (Self::Synodal, key) if SYNODAL_MERGED.contains(&key) => None,
+            (Self::Synodal, (book, chapter, verse)) => Some(
+                SYNODAL_SHIFTS
+                    .iter()
+                    .find(|&&(b, c, first, last, ..)| {
+                        b == book && c == chapter && (first..=last).contains(&verse)
+                    })
+                    .map_or(key, |&(_, _, first, _, kjv_chapter, kjv_first)| {
+                        (book, kjv_chapter, kjv_first + verse - first)
+                    })
+            ),
+            (Self::Kjv, _) => Some(key)
+        }
+    }
+
+    /// Converts a verse from KJV numbering.
+    #[must_use]
+    pub fn from_kjv(self, key: VerseKey) -> VerseKey {
+        match (self, key) {
+            (Self::Synodal, (PSALMS, chapter, verse)) => {
+                let (chapter, verse) =
+                    hebrew_to_synodal_psalm(chapter, verse + psalm_title_verses(chapter));
+                (PSALMS, chapter, verse)
+            }
+            (Self::Synodal, (ROMANS, 16, verse @ 25..=27)) => (ROMANS, 14, verse - 1),
+            (Self::Synodal, (book, chapter, verse)) => {
+                if let Some((_, synodal)) = KJV_MERGED.iter().find(|(kjv, _)| *kjv == key) {
+                    return *synodal;
+                }
+                SYNODAL_SHIFTS
+                    .iter()
+                    .find(|&&(b, _, first, last, kjv_chapter, kjv_first)| {
+                        b == book
+                            && kjv_chapter == chapter
+                            && (kjv_first..=kjv_first + last - first).contains(&verse)
+                    })
+                    .map_or(key, |&(_, synodal_chapter, first, _, _, kjv_first)| {
+                        (book, synodal_chapter, first + verse - kjv_first)
+                    })
+            }
+            (Self::Kjv, _) => key
+        }
+    }
+
+    /// Maps a verse of this scheme to another one.
+    #[must_use]
+    pub fn map(self, to: Self, key: VerseKey) -> Option<VerseKey> {
+        if self == to {
+            return Some(key);
+        }
+        self.to_kjv(key).map(|kjv| to.from_kjv(kjv))
+    }
+}
+
+/// Number of verses taken by a psalm title (Hebrew numbering).
+fn psalm_title_verses(chapter: i16) -> i16 {
+    if UNTITLED_PSALMS.contains(&chapter) {
+        0
+    } else if LONG_TITLE_PSALMS.contains(&chapter) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Synodal (LXX) psalm numbering to Hebrew, verses including titles.
+const fn synodal_to_hebrew_psalm(chapter: i16, verse: i16) -> (i16, i16) {
+    match chapter {
+        9 if verse > 21 => (10, verse - 21),
+        10..=112 | 116..=145 => (chapter + 1, verse),
+        113 if verse > 8 => (115, verse - 8),
+        113 => (114, verse),
+        114 => (116, verse),
+        115 => (116, verse + 9),
+        146 => (147, verse),
+        147 => (147, verse + 11),
+        _ => (chapter, verse)
+    }
+}
+
+/// Hebrew psalm numbering to Synodal (LXX), verses including titles.
+const fn hebrew_to_synodal_psalm(chapter: i16, verse: i16) -> (i16, i16) {
+    match chapter {
+        10 => (9, verse + 21),
+        11..=113 | 117..=146 => (chapter - 1, verse),
+        114 => (113, verse),
+        115 => (113, verse + 8),
+        116 if verse > 9 => (115, verse - 9),
+        116 => (114, verse),
+        147 if verse > 11 => (147, verse - 11),
+        147 => (146, verse),
+        _ => (chapter, verse)
+    }
+}
@@ -1,4 +1,9 @@
 const CACHE_NAME = 'revelation-v1';
+// Chapters downloaded for offline listening, managed by the app
+const AUDIO_CACHE = 'revelation-audio';
+// Offline commentary pack, managed by the app
+const COMMENTARY_CACHE = 'revelation-commentary';
+const APP_CACHES = [CACHE_NAME, AUDIO_CACHE, COMMENTARY_CACHE];
 const STATIC_ASSETS = [
   '/',
   '/bible',
@@ -15,7 +20,11 @@ self.addEventListener('install', (event) => {
 self.addEventListener('activate', (event) => {
   event.waitUntil(
     caches.keys().then((keys) =>
-      Promise.all(keys.filter((k) => k !== CACHE_NAME).map((k) => caches.delete(k)))
+      Promise.all(
+        keys
+          .filter((k) => !APP_CACHES.includes(k))
+          .map((k) => caches.delete(k))
+      )
     )
   );
   self.clients.claim();
@@ -24,6 +33,16 @@ self.addEventListener('activate', (event) => {
 self.addEventListener('fetch', (event) => {
   if (event.request.method !== 'GET') return;
 
+  // Downloaded audio is served from its cache first, without a network round trip
+  if (/\/audio\/.+\.(mp3|json)$/.test(new URL(event.request.url).pathname)) {
+    event.respondWith(
+      caches.open(AUDIO_CACHE)
+        .then((cache) => cache.match(event.request.url, { ignoreVary: true }))
+        .then((hit) => (hit ? fromCache(hit, event.request) : fetch(event.request)))
+    );
+    return;
+  }
+
   event.respondWith(
     fetch(event.request)
       .then((response) => {
@@ -36,3 +55,38 @@ self.addEventListener('fetch', (event) => {
       .catch(() => caches.match(event.request))
   );
 });
+
+// Media elements request byte ranges; answer them with a slice of the cached file
+async function fromCache(hit, request) {
+  const range = request.headers.get('Range');
+  if (!range) return hit;
+
+  const body = await hit.arrayBuffer();
+  const size = body.byteLength;
+  const match = /^bytes=(\d*)-(\d*)$/.exec(range.trim());
+  let start = NaN;
+  let end = size - 1;
+  if (match && match[1] !== '') {
+    start = Number(match[1]);
+    if (match[2] !== '') end = Math.min(Number(match[2]), size - 1);
+  } else if (match && match[2] !== '') {
+    start = Math.max(size - Number(match[2]), 0);
+  }
+  if (!(start <= end)) {
+    return new Response(null, {
+      status: 416,
+      headers: { 'Content-Range': `bytes */${size}` }
+    });
+  }
+
+  return new Response(body.slice(start, end + 1), {
+    status: 206,
+    statusText: 'Partial Content',
+    headers: {
+      'Content-Type': hit.headers.get('Content-Type') || 'audio/mpeg',
+      'Content-Length': String(end - start + 1),
+      'Content-Range': `bytes ${start}-${end}/${size}`,
+      'Accept-Ranges': 'bytes'
+    }
+  });
+}